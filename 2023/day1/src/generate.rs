@@ -0,0 +1,63 @@
+//! Random calibration documents.
+
+use aoc_common::generate::{Generator, Rng};
+
+use crate::DIGITS_AS_STR;
+
+/// The shape of a generated document. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub lines: usize,
+    /// The most runs of letters, digits and spelled out digits on a line.
+    pub max_pieces: usize,
+    /// How likely each digit on a line is to be spelled out.
+    pub words: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            lines: 1000,
+            max_pieces: 8,
+            words: 0.5,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(lines: usize) -> Self {
+        Params {
+            lines,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Lines of lowercase letters with digits among them, some spelled out. Every line has at
+/// least one digit.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.lines {
+        let pieces = rng.range(1..=params.max_pieces.max(1));
+        let digit_at = rng.index(pieces);
+        for piece in 0..pieces {
+            if piece == digit_at || rng.chance(0.5) {
+                if rng.chance(params.words) {
+                    input.push_str(rng.choose(&DIGITS_AS_STR).0);
+                } else {
+                    input.push(char::from(b'1' + rng.range(0..9_u8)));
+                }
+            } else {
+                for _ in 0..rng.range(1..=5) {
+                    input.push(char::from(b'a' + rng.range(0..26_u8)));
+                }
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
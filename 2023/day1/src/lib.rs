@@ -1,4 +1,8 @@
-const INPUT: &str = include_str!("../../data/input.txt");
+use aoc_common::generate::Rng;
+use aoc_common::{Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
 const RADIX: u32 = 10;
 const DIGITS_AS_STR: [(&str, u32); 9] = [
     ("one", 1),
@@ -12,15 +16,35 @@ const DIGITS_AS_STR: [(&str, u32); 9] = [
     ("nine", 9),
 ];
 
-fn main() {
-    let sum_of_calibrations = solve(INPUT);
-    println!("{}", sum_of_calibrations);
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(solve(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
 }
 
-fn solve(input: &str) -> u32 {
-    input.lines().fold(0, |sum, line| {
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    input.lines().try_fold(0, |sum, line| {
+        let has_digit = line.chars().any(|c| c.is_ascii_digit())
+            || DIGITS_AS_STR.iter().any(|(word, _)| line.contains(word));
+        if !has_digit {
+            return Err(ParseError::new(line, "expected a line with a digit"));
+        }
         let chars: Vec<char> = line.chars().collect();
-        sum + get_calibration_value(&chars)
+        Ok(sum + get_calibration_value(&chars))
     })
 }
 
@@ -34,7 +58,7 @@ fn get_calibration_value(chars: &[char]) -> u32 {
     let mut forward_string = String::new();
     let mut backward_string = String::new();
 
-    while !chars[start_ptr].is_numeric() {
+    while !chars[start_ptr].is_ascii_digit() {
         forward_string.push(chars[start_ptr]);
 
         for (digit_as_str, digit) in DIGITS_AS_STR {
@@ -49,7 +73,7 @@ fn get_calibration_value(chars: &[char]) -> u32 {
         start_ptr += 1;
     }
 
-    while !chars[end_ptr].is_numeric() {
+    while !chars[end_ptr].is_ascii_digit() {
         backward_string.push(chars[end_ptr]);
 
         for (digit_as_str, digit) in DIGITS_AS_STR {
@@ -100,3 +124,11 @@ mod test_get_calibration_value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day1);
+    aoc_common::generated_tests!(Day1, [1, 20, 200]);
+}
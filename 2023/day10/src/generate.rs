@@ -0,0 +1,130 @@
+//! Random fields of pipes.
+
+use aoc_common::generate::{outline, region, Generator, Rng};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// The shape of a generated field. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the field, at least 3.
+    pub side: usize,
+    /// About how much of the field the loop goes round.
+    pub fill: f64,
+    /// How likely each tile off the loop is to be a pipe rather than ground.
+    pub junk: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 140,
+            fill: 0.5,
+            junk: 0.8,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square field with one loop of pipes through `S`, among pipes that lead nowhere. Only the
+/// two pipes of the loop next to `S` connect to it.
+///
+/// The loop is the outline of a random shape drawn at twice the scale, so that it never runs
+/// alongside itself with no gap.
+///
+/// Panics if `side` is less than 3.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.side >= 3, "a loop needs a field at least 3 wide");
+    let cells = (params.side - 1) / 2;
+    let shape = region(
+        rng,
+        cells,
+        cells,
+        (params.fill * (cells * cells) as f64) as usize,
+    );
+    let offset = rng.range(0..=params.side - (2 * cells + 1));
+
+    // Walk the outline, two tiles per unit of the shape
+    let corners = outline(&shape);
+    let mut path = Vec::new();
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        let step = Point::new((next.x - corner.x).signum(), (next.y - corner.y).signum());
+        let mut point = corner * 2;
+        while point != next * 2 {
+            path.push((point.y as usize + offset, point.x as usize + offset));
+            point = point + step;
+        }
+    }
+
+    let mut field = Grid::filled(params.side, params.side, '.');
+    for position in field.positions().collect::<Vec<_>>() {
+        if rng.chance(params.junk) {
+            field[position] = *rng.choose(&PIPES);
+        }
+    }
+    for (i, &position) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        field[position] = pipe(direction(position, previous), direction(position, next));
+    }
+
+    let start = path[rng.index(path.len())];
+    field[start] = 'S';
+    for direction in Direction::ALL {
+        let Some(neighbor) = field.step(start, direction) else {
+            continue;
+        };
+        if !path.contains(&neighbor) && leads(field[neighbor], direction.reverse()) {
+            field[neighbor] = '.';
+        }
+    }
+    field.to_string()
+}
+
+fn direction(from: (usize, usize), to: (usize, usize)) -> Direction {
+    match (
+        to.0 as isize - from.0 as isize,
+        to.1 as isize - from.1 as isize,
+    ) {
+        (-1, 0) => Direction::Up,
+        (1, 0) => Direction::Down,
+        (0, -1) => Direction::Left,
+        _ => Direction::Right,
+    }
+}
+
+fn pipe(a: Direction, b: Direction) -> char {
+    *PIPES
+        .iter()
+        .find(|&&pipe| leads(pipe, a) && leads(pipe, b))
+        .expect("a loop never turns back on itself")
+}
+
+// Whether `pipe` has an end facing `direction`
+fn leads(pipe: char, direction: Direction) -> bool {
+    let ends: &[Direction] = match pipe {
+        '|' => &[Direction::Up, Direction::Down],
+        '-' => &[Direction::Left, Direction::Right],
+        'L' => &[Direction::Up, Direction::Right],
+        'J' => &[Direction::Up, Direction::Left],
+        '7' => &[Direction::Down, Direction::Left],
+        'F' => &[Direction::Down, Direction::Right],
+        _ => &[],
+    };
+    ends.contains(&direction)
+}
@@ -0,0 +1,348 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::{Grid, Position};
+use aoc_common::polygon::Polygon;
+use aoc_common::search::bfs_distances;
+use aoc_common::visualize::{frame, is_recording, Pixel, Rgb, Visualize};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum PipeKind {
+    Vertical,   // |
+    Horizontal, // -
+    NorthEast,  // L
+    NorthWest,  // J
+    SouthWest,  // 7
+    SouthEast,  // F
+    Ground,     // .
+    Start,      // S
+}
+
+impl PipeKind {
+    fn from(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(PipeKind::Vertical),
+            '-' => Some(PipeKind::Horizontal),
+            'L' => Some(PipeKind::NorthEast),
+            'J' => Some(PipeKind::NorthWest),
+            '7' => Some(PipeKind::SouthWest),
+            'F' => Some(PipeKind::SouthEast),
+            '.' => Some(PipeKind::Ground),
+            'S' => Some(PipeKind::Start),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            PipeKind::Vertical => '|',
+            PipeKind::Horizontal => '-',
+            PipeKind::NorthEast => '┕',
+            PipeKind::NorthWest => '┙',
+            PipeKind::SouthWest => '┑',
+            PipeKind::SouthEast => '┍',
+            PipeKind::Ground => '.',
+            PipeKind::Start => 'S',
+        }
+    }
+
+    fn can_travel(&self) -> Vec<Direction> {
+        match self {
+            PipeKind::Vertical => vec![Direction::Up, Direction::Down],
+            PipeKind::Horizontal => vec![Direction::Right, Direction::Left],
+            PipeKind::NorthEast => vec![Direction::Up, Direction::Right],
+            PipeKind::NorthWest => vec![Direction::Up, Direction::Left],
+            PipeKind::SouthWest => vec![Direction::Down, Direction::Left],
+            PipeKind::SouthEast => vec![Direction::Down, Direction::Right],
+            PipeKind::Start => Direction::ALL.to_vec(),
+            PipeKind::Ground => vec![],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Pipe {
+    row: usize,
+    column: usize,
+    kind: PipeKind,
+    is_start: bool,
+}
+
+impl Pipe {
+    fn from((row, column): Position, c: char) -> Option<Self> {
+        let kind = PipeKind::from(c)?;
+        Some(Self {
+            row,
+            column,
+            kind,
+            is_start: kind == PipeKind::Start,
+        })
+    }
+}
+
+// The map with the loop drawn in as far as `reached` steps from the start, shaded by how far
+// each pipe is along it
+struct Loop<'a> {
+    pipes: &'a Grid<Pipe>,
+    steps: &'a HashMap<Position, usize>,
+    reached: usize,
+}
+
+impl Visualize for Loop<'_> {
+    fn draw(&self) -> Grid<Pixel> {
+        let farthest = self.steps.values().max().copied().unwrap_or(0).max(1);
+        let mut pixels = self
+            .pipes
+            .map(|pipe| Pixel::new(pipe.kind.as_char(), Rgb::GRAY));
+        for (&position, &steps) in self.steps {
+            if steps <= self.reached {
+                let color = Rgb::GREEN.mix(Rgb::RED, steps as f64 / farthest as f64);
+                pixels[position].color = color;
+            }
+        }
+        pixels[start(self.pipes)].color = Rgb::WHITE;
+        pixels
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+fn deserialize(input: &str) -> Result<Grid<Pipe>, ParseError> {
+    let pipes = parse_phase(|| Grid::parse_with_position(input, Pipe::from))?;
+    if pipes.find(|pipe| pipe.is_start).is_none() {
+        return Err(ParseError::end(input, "no start tile `S` in the map"));
+    }
+    if let Some((second, tile)) = input.match_indices('S').nth(1) {
+        return Err(ParseError::new(
+            &input[second..second + tile.len()],
+            "more than one start tile in the map",
+        ));
+    }
+    Ok(pipes)
+}
+
+fn start(pipes: &Grid<Pipe>) -> Position {
+    pipes
+        .find(|pipe| pipe.is_start)
+        .expect("checked when parsing")
+}
+
+// The pipes next to `position` that it connects to. Pipes are only connected if each leads
+// into the other.
+fn connections(pipes: &Grid<Pipe>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    pipes[position]
+        .kind
+        .can_travel()
+        .into_iter()
+        .filter_map(move |dir| {
+            let next = pipes.step(position, dir)?;
+            pipes[next]
+                .kind
+                .can_travel()
+                .contains(&dir.reverse())
+                .then_some(next)
+        })
+}
+
+// The pipes of the loop in order, walking round it from the start. Pipes leading into the
+// start can also be dead ends, so each is tried until one leads back round. Other pipes have at
+// most two connections, but a walk can still circle a loop that skips the start, so one longer
+// than the map is given up on. `input` is the map `pipes` was parsed from, to point at the start.
+fn loop_path(input: &str, pipes: &Grid<Pipe>) -> Result<Vec<Position>, ParseError> {
+    let start = start(pipes);
+    let tiles = pipes.width() * pipes.height();
+    'first: for first in connections(pipes, start) {
+        let mut path = vec![start];
+        let (mut previous, mut current) = (start, first);
+        while current != start {
+            if path.len() > tiles {
+                continue 'first;
+            }
+            path.push(current);
+            let Some(next) = connections(pipes, current).find(|&next| next != previous) else {
+                continue 'first;
+            };
+            (previous, current) = (current, next);
+        }
+        return Ok(path);
+    }
+    let tile = input.find('S').expect("checked when parsing");
+    Err(ParseError::new(
+        &input[tile..tile + 1],
+        "the start isn't on a loop",
+    ))
+}
+
+// The steps from the start to every pipe in the loop, going whichever way round is shorter.
+// Branches off the loop are left out, as they can lead farther than halfway round it.
+fn loop_steps(pipes: &Grid<Pipe>, path: &[Position]) -> HashMap<Position, usize> {
+    let on_loop: HashSet<Position> = path.iter().copied().collect();
+    let on_loop = &on_loop;
+    bfs_distances(path[0], |&position| {
+        connections(pipes, position).filter(move |next| on_loop.contains(next))
+    })
+}
+
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    let pipes = deserialize(input)?;
+
+    let main_loop = loop_steps(&pipes, &loop_path(input, &pipes)?);
+    let farthest = main_loop.values().max().copied().unwrap_or(0);
+    if is_recording() {
+        // spread out from the start in about a hundred frames
+        for reached in (0..farthest).step_by(farthest.div_ceil(100).max(1)) {
+            frame(&Loop {
+                pipes: &pipes,
+                steps: &main_loop,
+                reached,
+            });
+        }
+        frame(&Loop {
+            pipes: &pipes,
+            steps: &main_loop,
+            reached: farthest,
+        });
+    }
+
+    // the farthest point is halfway round the loop
+    Ok(farthest as u64)
+}
+
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    let pipes = deserialize(input)?;
+
+    // the enclosed tiles are the points strictly inside the loop traced through tile centres
+    let path = loop_path(input, &pipes)?;
+    if is_recording() {
+        let steps = (0..)
+            .zip(&path)
+            .map(|(i, &position)| (position, i))
+            .collect();
+        frame(&Loop {
+            pipes: &pipes,
+            steps: &steps,
+            reached: path.len(),
+        });
+    }
+    let main_loop: Polygon = path.into_iter().map(Point::from).collect();
+    Ok(main_loop.interior_points() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day10);
+    aoc_common::generated_tests!(Day10, [3, 4, 21, 60]);
+
+    #[test]
+    fn test_branch_into_start() {
+        // The `|` above the start leads into it but isn't part of the loop
+        let input = "\
+.|...
+.S-7.
+.|.|.
+.L-J.
+";
+        assert_eq!(part_1(input), Ok(4));
+        assert_eq!(part_2(input), Ok(1));
+        let error = part_1("S-\n").unwrap_err().locate("S-\n");
+        assert_eq!(error.position, Some((1, 1)));
+    }
+
+    #[test]
+    fn test_long_branch_into_start() {
+        // The `|`s above the start lead farther from it than anywhere on the loop
+        let input = "\
+|....
+|....
+|....
+|....
+|....
+S-7..
+|.|..
+L-J..
+";
+        assert_eq!(part_1(input), Ok(4));
+        assert_eq!(part_2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_loop_past_start() {
+        // The walk from the first `S` ends up going round the loop through the second forever
+        let input = "\
+S-S-7
+..|.|
+..L-J
+";
+        assert!(part_1(input).is_err());
+        assert!(part_2(input).is_err());
+    }
+
+    #[test]
+    fn test_deser() {
+        let input = "\
+|-LJ7F.S
+..F7JL-|\
+";
+
+        let expected = Grid::from_cells(
+            8,
+            vec![
+                Pipe::from((0, 0), '|').unwrap(),
+                Pipe::from((0, 1), '-').unwrap(),
+                Pipe::from((0, 2), 'L').unwrap(),
+                Pipe::from((0, 3), 'J').unwrap(),
+                Pipe::from((0, 4), '7').unwrap(),
+                Pipe::from((0, 5), 'F').unwrap(),
+                Pipe::from((0, 6), '.').unwrap(),
+                Pipe::from((0, 7), 'S').unwrap(),
+                Pipe::from((1, 0), '.').unwrap(),
+                Pipe::from((1, 1), '.').unwrap(),
+                Pipe::from((1, 2), 'F').unwrap(),
+                Pipe::from((1, 3), '7').unwrap(),
+                Pipe::from((1, 4), 'J').unwrap(),
+                Pipe::from((1, 5), 'L').unwrap(),
+                Pipe::from((1, 6), '-').unwrap(),
+                Pipe::from((1, 7), '|').unwrap(),
+            ],
+        );
+
+        assert_eq!(deserialize(input), Ok(expected));
+        assert_eq!(
+            deserialize("|-\nL.").unwrap_err().to_string(),
+            "no start tile `S` in the map"
+        );
+        let input = "S-S\n|.|\nL-J\n";
+        assert_eq!(
+            deserialize(input).unwrap_err().locate(input).position,
+            Some((1, 3))
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Random images of galaxies.
+
+use aoc_common::generate::{Generator, Rng};
+use aoc_common::grid::Grid;
+
+/// The shape of a generated image. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the image.
+    pub side: usize,
+    /// How likely each tile outside the empty rows and columns is to be a galaxy.
+    pub galaxies: f64,
+    /// How likely each row and each column is to be left empty, to be expanded.
+    pub empty: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 140,
+            galaxies: 0.023,
+            empty: 0.05,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square image of `.` and `#` where exactly the rows and columns picked to be empty have
+/// no galaxies.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let side = params.side;
+    let empty_rows: Vec<bool> = (0..side).map(|_| rng.chance(params.empty)).collect();
+    let empty_columns: Vec<bool> = (0..side).map(|_| rng.chance(params.empty)).collect();
+    let open = |(row, column): (usize, usize)| !empty_rows[row] && !empty_columns[column];
+
+    let mut image = Grid::filled(side, side, '.');
+    for position in image.positions().collect::<Vec<_>>() {
+        if open(position) && rng.chance(params.galaxies) {
+            image[position] = '#';
+        }
+    }
+
+    // Give any row or column left empty by chance a galaxy of its own
+    let open_rows: Vec<usize> = (0..side).filter(|&row| !empty_rows[row]).collect();
+    let open_columns: Vec<usize> = (0..side).filter(|&column| !empty_columns[column]).collect();
+    if open_rows.is_empty() || open_columns.is_empty() {
+        return image.to_string();
+    }
+    for &row in &open_rows {
+        if !image.row(row).contains(&'#') {
+            image[(row, *rng.choose(&open_columns))] = '#';
+        }
+    }
+    for &column in &open_columns {
+        if image.column(column).all(|&c| c == '.') {
+            image[(*rng.choose(&open_rows), column)] = '#';
+        }
+    }
+    image.to_string()
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::{debug, trace};
+
+pub mod generate;
+#[cfg(test)]
+mod reference;
+
+#[derive(Debug)]
+struct Galaxy {
+    name: usize,
+    row: usize,
+    column: usize,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input, 1_000_000).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+// The image as a grid of `.` and `#`
+fn parse_image(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c))
+}
+
+pub fn part_2(input: &str, multiplier: usize) -> Result<usize, ParseError> {
+    let (space, extra_row_indexes, extra_col_indexes) = parse_phase(|| {
+        let space = parse_image(input)?;
+
+        let mut extra_row_indexes: Vec<usize> = Vec::new();
+        let mut extra_col_indexes: Vec<usize> = Vec::new();
+
+        for (i, line) in space.rows().enumerate() {
+            if !line.contains(&'#') {
+                extra_row_indexes.push(i)
+            }
+        }
+
+        for (i, mut column) in space.columns().enumerate() {
+            if column.all(|&c| c == '.') {
+                extra_col_indexes.push(i);
+            }
+        }
+
+        debug!(
+            height = space.height(),
+            width = space.width(),
+            ?extra_row_indexes,
+            ?extra_col_indexes,
+            "found empty space to expand"
+        );
+
+        Ok((space, extra_row_indexes, extra_col_indexes))
+    })?;
+
+    let mut map: HashMap<usize, Galaxy> = HashMap::new();
+    let mut keys: Vec<usize> = Vec::new();
+
+    let mut plus_rows = 0;
+    let mut plus_cols = 0;
+
+    for (row, r) in space.rows().enumerate() {
+        // if its a row that needs extra add to the plus rows
+        if extra_row_indexes.contains(&row) {
+            plus_rows += multiplier - 1;
+            continue;
+        }
+
+        for (col, &c) in r.iter().enumerate() {
+            if extra_col_indexes.iter().any(|i| i == &col) {
+                plus_cols += multiplier - 1;
+                continue;
+            }
+
+            if c == '#' {
+                let n = map.len();
+                let g = Galaxy {
+                    name: n,
+                    row: row + plus_rows,
+                    column: col + plus_cols,
+                };
+                map.insert(n, g);
+                keys.push(n);
+            }
+        }
+        // reset plus cols for every row
+        plus_cols = 0;
+    }
+
+    trace!(?map, "galaxies after expanding");
+
+    let mut shortest_path_sum = 0;
+
+    for (name, gal) in &map {
+        let cur_positon = keys.iter().position(|k| k == name).expect("will have");
+        let _rem = keys.remove(cur_positon);
+
+        for otr in keys.iter() {
+            match map.get(otr) {
+                Some(otr_gal) => {
+                    let shortest_path = usize::abs_diff(gal.column, otr_gal.column)
+                        + usize::abs_diff(gal.row, otr_gal.row);
+                    trace!(from = gal.name, to = otr_gal.name, shortest_path);
+                    shortest_path_sum += shortest_path;
+                }
+                None => {
+                    // already matched and removed
+                    continue;
+                }
+            };
+        }
+    }
+
+    Ok(shortest_path_sum)
+}
+
+// Every empty row and column counts twice
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    part_2(input, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example_input;
+
+    aoc_common::example_tests!(Day11);
+    aoc_common::generated_tests!(Day11, [1, 10, 40]);
+
+    #[test]
+    fn test_against_reference() {
+        let generate = |rng: &mut Rng| {
+            let params = generate::Params {
+                side: rng.range(1..=12),
+                galaxies: 0.1,
+                empty: 0.2,
+            };
+            generate::generate(rng, &params)
+        };
+        for multiplier in [1, 2, 5] {
+            aoc_common::differential::assert_agree(
+                200,
+                generate,
+                |input| part_2(input, multiplier),
+                |input| Ok(reference::part_2(input, multiplier)),
+            );
+        }
+    }
+
+    #[test]
+    fn test_part_2_2() {
+        assert_eq!(part_2(&example_input!("example.txt"), 2), Ok(374))
+    }
+
+    #[test]
+    fn test_part_2_10() {
+        assert_eq!(part_2(&example_input!("example.txt"), 10), Ok(1030))
+    }
+
+    #[test]
+    fn test_part_2_100() {
+        assert_eq!(part_2(&example_input!("example.txt"), 100), Ok(8410))
+    }
+}
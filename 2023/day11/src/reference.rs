@@ -0,0 +1,35 @@
+//! Slow but obviously correct answers, to check the offsets added for empty space against.
+
+/// Copies every empty row and column until there are `multiplier` of it, then measures
+/// between every pair of galaxies.
+pub fn part_2(input: &str, multiplier: usize) -> usize {
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    for line in input.lines() {
+        let copies = if line.contains('#') { 1 } else { multiplier };
+        rows.extend(vec![line.chars().collect(); copies]);
+    }
+
+    let width = rows.first().map_or(0, Vec::len);
+    let mut columns: Vec<Vec<char>> = Vec::new();
+    for x in 0..width {
+        let column: Vec<char> = rows.iter().map(|row| row[x]).collect();
+        let copies = if column.contains(&'#') { 1 } else { multiplier };
+        columns.extend(vec![column; copies]);
+    }
+
+    let mut galaxies = Vec::new();
+    for (x, column) in columns.iter().enumerate() {
+        for (y, &c) in column.iter().enumerate() {
+            if c == '#' {
+                galaxies.push((x, y));
+            }
+        }
+    }
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            total += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+        }
+    }
+    total
+}
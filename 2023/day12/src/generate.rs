@@ -0,0 +1,69 @@
+//! Random condition records of springs.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated list of records. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub records: usize,
+    /// The most springs in a record, at least 1.
+    pub max_len: usize,
+    /// How likely each spring is to be damaged.
+    pub damaged: f64,
+    /// How likely each spring's condition is to be unknown.
+    pub unknown: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            records: 1000,
+            max_len: 20,
+            damaged: 0.5,
+            unknown: 0.5,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(records: usize) -> Self {
+        Params {
+            records,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Records like `???.### 1,1,3`, each made by hiding some springs of a real arrangement behind
+/// `?`, so there is always at least one way to fill them in. Every record has at least one
+/// damaged spring.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.records {
+        let len = rng.range(1..=params.max_len.max(1));
+        let mut springs: Vec<bool> = (0..len).map(|_| rng.chance(params.damaged)).collect();
+        if !springs.contains(&true) {
+            springs[rng.index(len)] = true;
+        }
+
+        let groups: Vec<String> = springs
+            .split(|&damaged| !damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let record: String = springs
+            .iter()
+            .map(|&damaged| match (rng.chance(params.unknown), damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        input.push_str(&format!("{record} {}\n", groups.join(",")));
+    }
+    input
+}
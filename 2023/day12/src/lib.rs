@@ -1,14 +1,10 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("./input.txt");
-// const TEST: &str = "\
-// ???.### 1,1,3
-// .??..??...?##. 1,1,3
-// ?#?#?#?#?#?#?#? 1,3,1,6
-// ????.#...#... 4,1,1
-// ????.######..#####. 1,6,5
-// ?###???????? 3,2,1\
-// ";
+use aoc_common::generate::Rng;
+use aoc_common::parse::{parse, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+#[cfg(test)]
+mod reference;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Condition {
@@ -18,12 +14,12 @@ enum Condition {
 }
 
 impl Condition {
-    fn from(c: char) -> Self {
+    fn from(c: char) -> Option<Self> {
         match c {
-            '.' => Condition::Operational,
-            '#' => Condition::Damaged,
-            '?' => Condition::Unknown,
-            _ => unreachable!("All chars are handled"),
+            '.' => Some(Condition::Operational),
+            '#' => Some(Condition::Damaged),
+            '?' => Some(Condition::Unknown),
+            _ => None,
         }
     }
 }
@@ -35,39 +31,64 @@ struct Record {
 }
 
 impl Record {
-    // for part 2
-    fn from_line(l: &str) -> Self {
-        let (springs, groups) = l.split_once(' ').unwrap();
+    // `copies` is 1 for part 1 and 5 once the records are unfolded for part 2
+    fn from_line(l: &str, copies: usize) -> Result<Self, ParseError> {
+        let (springs, groups) = split_once(l, " ")?;
 
-        let springs = [springs, springs, springs, springs, springs].join("?");
-        let springs = format!(".{}", springs.trim_end_matches('.'))
-            .chars()
-            .map(Condition::from)
-            .collect();
-
-        let groups = [groups, groups, groups, groups, groups].join(",");
+        let springs: Vec<Condition> = springs
+            .char_indices()
+            .map(|(i, c)| {
+                Condition::from(c).ok_or_else(|| {
+                    ParseError::new(&springs[i..i + c.len_utf8()], "expected `.`, `#` or `?`")
+                })
+            })
+            .collect::<Result<_, _>>()?;
         let groups: Vec<usize> = groups
             .split(',')
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect();
+            .map(|s| parse(s, "a group size"))
+            .collect::<Result<_, _>>()?;
+
+        // join the copies with `?`, lead with an operational spring and drop trailing ones
+        let mut unfolded = vec![Condition::Operational];
+        for copy in 0..copies {
+            if copy > 0 {
+                unfolded.push(Condition::Unknown);
+            }
+            unfolded.extend(&springs);
+        }
+        while unfolded.len() > 1 && unfolded.last() == Some(&Condition::Operational) {
+            unfolded.pop();
+        }
 
-        Self { springs, groups }
+        Ok(Self {
+            springs: unfolded,
+            groups: groups.repeat(copies),
+        })
     }
 }
 
-fn main() {
-    let s = Instant::now();
-    println!(
-        "Part 1 = {} in {:?}",
-        part_1(INPUT),
-        Instant::now().duration_since(s)
-    );
-    let s = Instant::now();
-    println!(
-        "Part 2 = {} in {:?}",
-        part_2(INPUT),
-        Instant::now().duration_since(s)
-    );
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
 }
 
 fn tabulation(record: &Record) -> usize {
@@ -106,47 +127,51 @@ fn tabulation(record: &Record) -> usize {
     table[record.groups.len()][record.springs.len()]
 }
 
-fn part_1(input: &str) -> usize {
-    input
-        .lines()
-        .map(|l| {
-            let (springs, groups) = l.split_once(' ').unwrap();
-            let springs = format!(".{}", springs.trim_end_matches('.'));
-            let springs: Vec<Condition> = springs.chars().map(Condition::from).collect();
-            let groups: Vec<usize> = groups
-                .split(',')
-                .map(|s| s.parse::<usize>().unwrap())
-                .collect();
-            Record { springs, groups }
-        })
-        .collect::<Vec<Record>>()
-        .iter()
-        .map(tabulation)
-        .sum::<usize>()
+fn deserialize(input: &str, copies: usize) -> Result<Vec<Record>, ParseError> {
+    parse_phase(|| {
+        input
+            .lines()
+            .map(|l| Record::from_line(l, copies))
+            .collect()
+    })
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    Ok(deserialize(input, 1)?.iter().map(tabulation).sum::<usize>())
 }
 
-fn part_2(input: &str) -> usize {
-    input
-        .lines()
-        .map(Record::from_line)
-        .collect::<Vec<Record>>()
-        .iter()
-        .map(tabulation)
-        .sum::<usize>()
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    Ok(deserialize(input, 5)?.iter().map(tabulation).sum::<usize>())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST: &str = "\
-???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1\
-";
+    aoc_common::example_tests!(Day12);
+    aoc_common::generated_tests!(Day12, [1, 20, 200]);
+
+    #[test]
+    fn test_against_reference() {
+        let small = |records, max_len| generate::Params {
+            records,
+            max_len,
+            ..generate::Params::default()
+        };
+        aoc_common::differential::assert_agree(
+            200,
+            |rng| generate::generate(rng, &small(4, 12)),
+            part_1,
+            |input| Ok(reference::part_1(input)),
+        );
+        // Unfolding makes five times the unknowns, and one more between each copy
+        aoc_common::differential::assert_agree(
+            50,
+            |rng| generate::generate(rng, &small(1, 3)),
+            part_2,
+            |input| Ok(reference::part_2(input)),
+        );
+    }
 
     #[test]
     fn test_tabulation_1() {
@@ -246,14 +271,4 @@ mod tests {
 
         assert_eq!(tabulation(&record), 1);
     }
-
-    #[test]
-    fn test_part_1_tab() {
-        assert_eq!(part_1(TEST), 21);
-    }
-
-    #[test]
-    fn test_part_2_tab() {
-        assert_eq!(part_2(TEST), 525152);
-    }
 }
@@ -0,0 +1,39 @@
+//! Slow but obviously correct answers, to check the tabulation against.
+
+/// Tries every way of filling in the unknown springs.
+fn arrangements(springs: &str, groups: &[usize]) -> usize {
+    let unknowns: Vec<usize> = springs.match_indices('?').map(|(i, _)| i).collect();
+    (0..1_u64 << unknowns.len())
+        .filter(|&fill| {
+            let mut springs = springs.as_bytes().to_vec();
+            for (bit, &i) in unknowns.iter().enumerate() {
+                springs[i] = if fill >> bit & 1 == 1 { b'#' } else { b'.' };
+            }
+            let found: Vec<usize> = springs
+                .split(|&c| c == b'.')
+                .filter(|run| !run.is_empty())
+                .map(|run| run.len())
+                .collect();
+            found == groups
+        })
+        .count()
+}
+
+fn total(input: &str, copies: usize) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let (springs, groups) = line.split_once(' ').unwrap();
+            let groups: Vec<usize> = groups.split(',').map(|g| g.parse().unwrap()).collect();
+            arrangements(&vec![springs; copies].join("?"), &groups.repeat(copies))
+        })
+        .sum()
+}
+
+pub fn part_1(input: &str) -> usize {
+    total(input, 1)
+}
+
+pub fn part_2(input: &str) -> usize {
+    total(input, 5)
+}
@@ -0,0 +1,98 @@
+//! Random notes on patterns of ash and rocks.
+
+use aoc_common::generate::{Generator, Rng};
+use aoc_common::grid::Grid;
+
+/// The shape of a generated set of notes. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub patterns: usize,
+    /// The least and most rows and columns in a pattern, at least 2.
+    pub min_side: usize,
+    pub max_side: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            patterns: 100,
+            min_side: 5,
+            max_side: 17,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(patterns: usize) -> Self {
+        Params {
+            patterns,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Patterns separated by blank lines, each with exactly one line of reflection, between rows
+/// or between columns, that is off by one smudge.
+///
+/// Panics if `min_side` is less than 2 or more than `max_side`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(
+        2 <= params.min_side && params.min_side <= params.max_side,
+        "patterns need at least two rows"
+    );
+    let patterns: Vec<String> = (0..params.patterns)
+        .map(|_| loop {
+            let pattern = pattern(rng, params);
+            if smudged_lines(&pattern) + smudged_lines(&pattern.transpose()) == 1 {
+                break pattern.to_string();
+            }
+        })
+        .collect();
+    patterns.join("\n")
+}
+
+// A pattern reflected about a line between two rows, or two columns, with one smudge in it
+fn pattern(rng: &mut Rng, params: &Params) -> Grid<char> {
+    let width = rng.range(params.min_side..=params.max_side);
+    let height = rng.range(params.min_side..=params.max_side);
+    let cells = (0..width * height)
+        .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+        .collect();
+    let mut pattern = Grid::from_cells(width, cells);
+
+    let line = rng.range(1..height);
+    let reach = line.min(height - line);
+    for i in 0..reach {
+        for column in 0..width {
+            pattern[(line + i, column)] = pattern[(line - 1 - i, column)];
+        }
+    }
+    let smudge = (line + rng.index(reach), rng.index(width));
+    pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+    if rng.chance(0.5) {
+        pattern.transpose()
+    } else {
+        pattern
+    }
+}
+
+// How many lines between rows the pattern reflects about with exactly one smudge
+fn smudged_lines(pattern: &Grid<char>) -> usize {
+    (1..pattern.height())
+        .filter(|&line| {
+            let reach = line.min(pattern.height() - line);
+            let smudges: usize = (0..reach)
+                .map(|i| {
+                    let (above, below) = (pattern.row(line - 1 - i), pattern.row(line + i));
+                    above.iter().zip(below).filter(|(a, b)| a != b).count()
+                })
+                .sum();
+            smudges == 1
+        })
+        .count()
+}
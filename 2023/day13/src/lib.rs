@@ -0,0 +1,137 @@
+use std::iter::zip;
+
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::parse::paragraphs;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(solve(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+fn deser(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    parse_phase(|| {
+        paragraphs(input)
+            .map(|pattern| Grid::parse(pattern, |c| matches!(c, '.' | '#').then_some(c)))
+            .collect()
+    })
+}
+
+// The rows with a reflection line just above them that needs exactly one smudge fixed
+fn find_row_mirrors(pattern_grid: &Grid<char>) -> Vec<usize> {
+    let pattern_len = pattern_grid.height();
+    let middle_index = pattern_len / 2;
+
+    let mut mirror_rows: Vec<usize> = Vec::new();
+    let mut row_idx = 1;
+
+    while row_idx < pattern_len {
+        let d = pattern_len - row_idx;
+        let (up, down) = if row_idx <= middle_index {
+            ((0, row_idx), (row_idx, row_idx + row_idx))
+        } else {
+            (
+                (usize::abs_diff(row_idx, d), row_idx),
+                (row_idx, row_idx + d),
+            )
+        };
+
+        let mut is_mirror = true;
+        let mut smudge_count = 0;
+
+        for (u, d) in zip((up.0..up.1).rev(), down.0..down.1) {
+            let up = pattern_grid.row(u).iter().collect::<String>();
+            let down = pattern_grid.row(d).iter().collect::<String>();
+
+            if up == down {
+                continue;
+            }
+            if one_char_apart(&up, &down) {
+                smudge_count += 1;
+                continue;
+            }
+
+            is_mirror = false;
+            break;
+        }
+
+        if is_mirror && smudge_count == 1 {
+            mirror_rows.push(row_idx);
+        }
+        row_idx += 1;
+    }
+
+    mirror_rows
+}
+
+fn find_row_mirror(pattern_grid: &Grid<char>) -> usize {
+    find_row_mirrors(pattern_grid)
+        .iter()
+        .fold(0, |a, c| a + c * 100)
+}
+
+// Columns are the rows of the transposed pattern
+fn find_column_mirror(pattern_grid: &Grid<char>) -> usize {
+    find_row_mirrors(&pattern_grid.transpose())
+        .iter()
+        .sum::<usize>()
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let pattern_grids = deser(input)?;
+
+    let mut total = 0;
+
+    for pattern_grid in pattern_grids {
+        let row = find_row_mirror(&pattern_grid);
+        if row != 0 {
+            total += row;
+            continue;
+        }
+        total += find_column_mirror(&pattern_grid);
+    }
+
+    Ok(total)
+}
+
+fn one_char_apart(str1: &str, str2: &str) -> bool {
+    if str1.len() != str2.len() {
+        return false;
+    }
+    let mut diff_count = 0;
+    for (char1, char2) in str1.chars().zip(str2.chars()) {
+        if char1 != char2 {
+            diff_count += 1;
+            if diff_count > 1 {
+                return false;
+            }
+        }
+    }
+    diff_count == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day13);
+    aoc_common::generated_tests!(Day13, [1, 10, 100]);
+}
@@ -0,0 +1,56 @@
+//! Random platforms of rocks.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated platform. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the platform.
+    pub side: usize,
+    /// How likely each tile is to hold a rounded rock, `O`.
+    pub rounded: f64,
+    /// How likely each tile is to hold a cube-shaped rock, `#`.
+    pub cubes: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 100,
+            rounded: 0.2,
+            cubes: 0.17,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square platform of `O`, `#` and `.`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.side {
+        for _ in 0..params.side {
+            let roll = rng.unit();
+            input.push(if roll < params.rounded {
+                'O'
+            } else if roll < params.rounded + params.cubes {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+use aoc_common::cycle::nth_state;
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::visualize::{frame, Pixel, Rgb, ToPixel};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::trace;
+
+pub mod generate;
+
+const ITERATIONS: usize = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Thing {
+    Rock,
+    Cube,
+    Space,
+}
+
+impl Thing {
+    fn from(c: char) -> Option<Thing> {
+        match c {
+            'O' => Some(Thing::Rock),
+            '#' => Some(Thing::Cube),
+            '.' => Some(Thing::Space),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Thing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Thing::Rock => 'O',
+            Thing::Cube => '#',
+            Thing::Space => '.',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl ToPixel for Thing {
+    fn to_pixel(&self) -> Pixel {
+        match self {
+            Thing::Rock => Pixel::new('O', Rgb::ORANGE),
+            Thing::Cube => Pixel::new('#', Rgb::GRAY),
+            Thing::Space => Pixel::new('.', Rgb::BLACK),
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let mut thing_grid = deserialize(input)?;
+    frame(&thing_grid);
+    slide_north(&mut thing_grid);
+    frame(&thing_grid);
+    Ok(calculate_load(&thing_grid))
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let thing_grid = deserialize(input)?;
+    frame(&thing_grid);
+
+    // the rocks soon settle into a loop, so skip ahead through it to the last cycle
+    let thing_grid = nth_state(
+        thing_grid,
+        |thing_grid| {
+            let mut thing_grid = thing_grid.clone();
+            cycle(&mut thing_grid);
+            trace!(load = calculate_load(&thing_grid), "spun");
+            thing_grid
+        },
+        ITERATIONS,
+    );
+
+    Ok(calculate_load(&thing_grid))
+}
+
+fn deserialize(input: &str) -> Result<Grid<Thing>, ParseError> {
+    parse_phase(|| Grid::parse(input, Thing::from))
+}
+
+fn cycle(thing_grid: &mut Grid<Thing>) {
+    slide_north(thing_grid);
+    frame(thing_grid);
+    slide_west(thing_grid);
+    frame(thing_grid);
+    slide_south(thing_grid);
+    frame(thing_grid);
+    slide_east(thing_grid);
+    frame(thing_grid);
+}
+
+fn calculate_load(thing_grid: &Grid<Thing>) -> usize {
+    thing_grid
+        .rows()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            let mut load = 0;
+            row.iter().for_each(|&c| {
+                if c == Thing::Rock {
+                    load += thing_grid.height() - row_idx
+                }
+            });
+            load
+        })
+        .sum::<usize>()
+}
+
+fn slide_north(thing_grid: &mut Grid<Thing>) {
+    let mut col_idx = 0;
+    while col_idx < thing_grid.width() {
+        let mut row_idx = 1;
+        while row_idx < thing_grid.height() {
+            let mut peek_idx = row_idx - 1;
+
+            while thing_grid[(peek_idx + 1, col_idx)] == Thing::Rock {
+                let peek = thing_grid[(peek_idx, col_idx)];
+                if peek == Thing::Space {
+                    // swap
+                    thing_grid[(peek_idx, col_idx)] = thing_grid[(peek_idx + 1, col_idx)];
+                    thing_grid[(peek_idx + 1, col_idx)] = peek;
+                } else {
+                    break;
+                }
+                if peek_idx == 0 {
+                    break;
+                } else {
+                    peek_idx -= 1;
+                }
+            }
+            row_idx += 1;
+        }
+        col_idx += 1;
+    }
+}
+
+fn slide_south(thing_grid: &mut Grid<Thing>) {
+    let height = thing_grid.height();
+    for col_idx in 0..thing_grid.width() {
+        // from the bottom up, so each rock rolls onto any that have already settled below it
+        for row_idx in (0..height.saturating_sub(1)).rev() {
+            let mut peek_idx = row_idx + 1;
+            while peek_idx < height
+                && thing_grid[(peek_idx - 1, col_idx)] == Thing::Rock
+                && thing_grid[(peek_idx, col_idx)] == Thing::Space
+            {
+                thing_grid[(peek_idx, col_idx)] = Thing::Rock;
+                thing_grid[(peek_idx - 1, col_idx)] = Thing::Space;
+                peek_idx += 1;
+            }
+        }
+    }
+}
+
+fn slide_east(thing_grid: &mut Grid<Thing>) {
+    for row in thing_grid.rows_mut() {
+        // from the right, so each rock rolls onto any that have already settled beside it
+        for col_idx in (0..row.len().saturating_sub(1)).rev() {
+            let mut peek_idx = col_idx + 1;
+            while peek_idx < row.len()
+                && row[peek_idx - 1] == Thing::Rock
+                && row[peek_idx] == Thing::Space
+            {
+                row[peek_idx] = Thing::Rock;
+                row[peek_idx - 1] = Thing::Space;
+                peek_idx += 1;
+            }
+        }
+    }
+}
+
+fn slide_west(thing_grid: &mut Grid<Thing>) {
+    for row in thing_grid.rows_mut() {
+        let mut col_idx = 1;
+        while col_idx < row.len() {
+            let mut peek_idx = col_idx - 1;
+            while row[peek_idx + 1] == Thing::Rock {
+                let peek = row[peek_idx];
+                if peek != Thing::Space {
+                    break;
+                }
+                // swap
+                row[peek_idx] = row[peek_idx + 1];
+                row[peek_idx + 1] = peek;
+
+                if peek_idx == 0 {
+                    break;
+                } else {
+                    peek_idx -= 1;
+                }
+            }
+            col_idx += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day14);
+    aoc_common::generated_tests!(Day14, [1, 10, 30]);
+
+    #[test]
+    fn test_one_row_or_column() {
+        assert_eq!(part_1("O.#\n"), Ok(1));
+        assert_eq!(part_2("O.#\n"), Ok(1));
+        assert_eq!(part_2("O\n.\n#\n"), Ok(2));
+        assert_eq!(part_2("O\n"), Ok(1));
+    }
+}
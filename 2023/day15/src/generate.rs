@@ -0,0 +1,67 @@
+//! Random initialization sequences.
+
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated sequence. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub steps: usize,
+    /// How many different labels the steps use.
+    pub labels: usize,
+    /// How likely each step is to remove a lens rather than put one in.
+    pub removals: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            steps: 4000,
+            labels: 535,
+            removals: 0.5,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(steps: usize) -> Self {
+        Params {
+            steps,
+            labels: steps.div_ceil(8).max(1),
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// One line of comma separated steps like `rn=1` and `cm-`, with no newline at the end.
+/// Labels are two to six lowercase letters, mostly short ones.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut labels = HashSet::new();
+    while labels.len() < params.labels.max(1) {
+        // about half again as many labels of each length as of the next
+        let len = 2 + (0..4).take_while(|_| rng.chance(0.6)).count();
+        let label: String = (0..len)
+            .map(|_| char::from(b'a' + rng.range(0..26_u8)))
+            .collect();
+        labels.insert(label);
+    }
+    let mut labels: Vec<String> = labels.into_iter().collect();
+    labels.sort_unstable();
+
+    let steps: Vec<String> = (0..params.steps)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(params.removals) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",")
+}
@@ -1,44 +1,69 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-const INPUT: &str = include_str!("./input.txt");
+use aoc_common::generate::Rng;
+use aoc_common::parse::parse;
+use aoc_common::{Answer, ParseError, PartResult, Solution};
+use tracing::trace;
 
-fn main() {
-    // let solve = part_1(INPUT);
-    let solve = part_2(INPUT);
-    println!("{solve}")
+pub mod generate;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(Ok(part_1(input).into()))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
 }
 
-fn part_1(input: &str) -> usize {
-    let s = Instant::now();
-    let ans = input.split(',').map(hash).sum();
-    let e = Instant::now();
-    println!("Processed in {:?}", e.duration_since(s));
+// The comma-separated steps; the line ending at the end of the input isn't part of the last one
+fn steps(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches(['\r', '\n']).split(',')
+}
 
-    ans
+pub fn part_1(input: &str) -> usize {
+    steps(input).map(hash).sum()
 }
 
-fn part_2(input: &str) -> usize {
-    let s = Instant::now();
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
     let mut map: HashMap<usize, Vec<String>> = HashMap::with_capacity(225);
-    for s in input.split(',') {
+    for s in steps(input) {
         let mut operation = '.';
         let (label, focal_length) = s
             .split_once(|c| match c {
                 '-' => {
                     operation = '-';
-                    return true;
+                    true
                 }
                 '=' => {
                     operation = '=';
-                    return true;
+                    true
                 }
                 _ => false,
             })
-            .expect("matches example");
+            .ok_or_else(|| ParseError::new(s, "expected a `-` or `=` step"))?;
+        if operation == '=' {
+            parse::<usize>(focal_length, "a focal length")?;
+        }
 
         let h = hash(label);
 
-        // println!("STEP: {s} OPERATION: {operation} LABEL: {label} HASH: {h}");
+        trace!(step = s, %operation, label, hash = h);
 
         map.entry(h)
             .and_modify(|e| match operation {
@@ -77,10 +102,7 @@ fn part_2(input: &str) -> usize {
         }
     }
 
-    let e = Instant::now();
-    println!("Processed in {:?}", e.duration_since(s));
-
-    total
+    Ok(total)
 }
 
 fn hash(s: &str) -> usize {
@@ -90,24 +112,12 @@ fn hash(s: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn test_hash() {
-        assert_eq!(hash("HASH"), 52)
-    }
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(
-            part_1("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
-            1320
-        )
-    }
+    aoc_common::example_tests!(Day15);
+    aoc_common::generated_tests!(Day15, [1, 20, 500]);
 
     #[test]
-    fn test_part_2() {
-        assert_eq!(
-            part_2("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
-            145
-        )
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52)
     }
 }
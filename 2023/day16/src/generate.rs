@@ -0,0 +1,52 @@
+//! Random contraptions of mirrors and splitters.
+
+use aoc_common::generate::{Generator, Rng};
+
+const DEVICES: [char; 4] = ['/', '\\', '-', '|'];
+
+/// The shape of a generated contraption. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the contraption.
+    pub side: usize,
+    /// How likely each tile is to hold a mirror or a splitter, each kind as likely as another.
+    pub devices: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 110,
+            devices: 0.1,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square contraption of `/`, `\`, `-` and `|` among `.`s.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.side {
+        for _ in 0..params.side {
+            input.push(if rng.chance(params.devices) {
+                *rng.choose(&DEVICES)
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
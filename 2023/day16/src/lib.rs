@@ -0,0 +1,218 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::generate::Rng;
+use aoc_common::geometry::Direction;
+use aoc_common::grid::{Grid, Position};
+use aoc_common::visualize::{frame, Pixel, Rgb, ToPixel, Visualize};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Kind {
+    Space,
+    RightMirror,
+    LeftMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl Kind {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Space),
+            '/' => Some(Self::RightMirror),
+            '\\' => Some(Self::LeftMirror),
+            '-' => Some(Self::HorizontalSplitter),
+            '|' => Some(Self::VerticalSplitter),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Tile {
+    is_energized: bool,
+    kind: Kind,
+}
+
+impl ToPixel for Tile {
+    fn to_pixel(&self) -> Pixel {
+        let glyph = match self.kind {
+            Kind::Space => '.',
+            Kind::RightMirror => '/',
+            Kind::LeftMirror => '\\',
+            Kind::HorizontalSplitter => '-',
+            Kind::VerticalSplitter => '|',
+        };
+        let color = match (self.is_energized, self.kind) {
+            (true, _) => Rgb::YELLOW,
+            (false, Kind::Space) => Rgb::BLACK,
+            (false, _) => Rgb::GRAY,
+        };
+        Pixel::new(glyph, color)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    // The tile the beam is entering
+    pos: Position,
+    dir: Direction,
+}
+
+// The contraption with the beams on their way through it
+struct Contraption<'a> {
+    grid: &'a Grid<Tile>,
+    beams: &'a VecDeque<Beam>,
+}
+
+impl Visualize for Contraption<'_> {
+    fn draw(&self) -> Grid<Pixel> {
+        let mut pixels = self.grid.draw();
+        for beam in self.beams {
+            let glyph = match beam.dir {
+                Direction::Up => '^',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+                Direction::Right => '>',
+            };
+            pixels[beam.pos] = Pixel::new(glyph, Rgb::WHITE);
+        }
+        pixels
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let grid = deserialize(input)?;
+    Ok(energize(grid, (0, 0), Direction::Right))
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let grid = deserialize(input)?;
+    let (last_row, last_col) = (grid.height() - 1, grid.width() - 1);
+
+    let mut starts = Vec::new();
+    for row in 0..grid.height() {
+        starts.push(((row, last_col), Direction::Left));
+        starts.push(((row, 0), Direction::Right));
+    }
+    for col in 0..grid.width() {
+        starts.push(((0, col), Direction::Down));
+        starts.push(((last_row, col), Direction::Up));
+    }
+
+    Ok(starts
+        .into_iter()
+        .map(|(pos, dir)| energize(grid.clone(), pos, dir))
+        .max()
+        .unwrap_or(0))
+}
+
+// Follows a beam entering the grid at `start` heading `dir`, and counts the tiles it energizes.
+fn energize(mut grid: Grid<Tile>, start: Position, dir: Direction) -> usize {
+    // Every beam moves one tile per round, in the order they were split off
+    let mut beams = VecDeque::from([Beam { pos: start, dir }]);
+    // A beam entering a tile the same way as one before it would only follow that one's path
+    let mut seen = HashSet::new();
+
+    while !beams.is_empty() {
+        frame(&Contraption {
+            grid: &grid,
+            beams: &beams,
+        });
+        for _ in 0..beams.len() {
+            let beam = beams.pop_front().expect("one pop per beam in the round");
+            if seen.insert(beam) {
+                step(&mut grid, &mut beams, beam);
+            }
+        }
+    }
+
+    grid.iter().filter(|(_, tile)| tile.is_energized).count()
+}
+
+// Moves `beam` through the tile it is entering, queueing the beams that leave it
+fn step(grid: &mut Grid<Tile>, beams: &mut VecDeque<Beam>, beam: Beam) {
+    let tile = grid[beam.pos];
+    let mut dirs = vec![];
+
+    match tile.kind {
+        Kind::Space => dirs.push(beam.dir),
+        // `/` turns a beam going up or down to the right, and one going across to the left
+        Kind::RightMirror if beam.dir.is_vertical() => dirs.push(beam.dir.turn_right()),
+        Kind::RightMirror => dirs.push(beam.dir.turn_left()),
+        Kind::LeftMirror if beam.dir.is_vertical() => dirs.push(beam.dir.turn_left()),
+        Kind::LeftMirror => dirs.push(beam.dir.turn_right()),
+        Kind::HorizontalSplitter => match beam.dir {
+            Direction::Up | Direction::Down => dirs.extend([Direction::Right, Direction::Left]),
+            Direction::Left | Direction::Right => dirs.push(beam.dir),
+        },
+        Kind::VerticalSplitter => match beam.dir {
+            Direction::Up | Direction::Down => dirs.push(beam.dir),
+            Direction::Left | Direction::Right => dirs.extend([Direction::Up, Direction::Down]),
+        },
+    }
+    grid[beam.pos].is_energized = true;
+
+    for dir in dirs {
+        if let Some(pos) = grid.step(beam.pos, dir) {
+            beams.push_back(Beam { pos, dir });
+        }
+    }
+}
+
+fn deserialize(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid = parse_phase(|| {
+        Grid::parse(input, |c| {
+            Some(Tile {
+                is_energized: false,
+                kind: Kind::from_char(c)?,
+            })
+        })
+    })?;
+    if grid.width() == 0 {
+        return Err(ParseError::end(input, "expected a map of the contraption"));
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day16);
+    aoc_common::generated_tests!(Day16, [1, 10, 40]);
+
+    #[test]
+    fn test_beams_loop() {
+        // Beams circle round the mirrors, and back through the splitter end-on
+        let input = "/.\\\n\\-/\n";
+        assert_eq!(part_1(input), Ok(1));
+        assert_eq!(part_2(input), Ok(6));
+    }
+}
@@ -0,0 +1,48 @@
+//! Random maps of heat loss.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated map. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the map, at least 5 so an ultra crucible can reach the end.
+    pub side: usize,
+    /// The most heat lost on a block, from 1 to 9.
+    pub max_loss: u8,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 141,
+            max_loss: 9,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square map of digits from 1 to `max_loss`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.side {
+        for _ in 0..params.side {
+            input.push(char::from(
+                b'0' + rng.range(1..=params.max_loss.clamp(1, 9)),
+            ));
+        }
+        input.push('\n');
+    }
+    input
+}
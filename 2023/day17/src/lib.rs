@@ -0,0 +1,162 @@
+use aoc_common::generate::Rng;
+use aoc_common::geometry::Direction;
+use aoc_common::grid::{Grid, Position};
+use aoc_common::search::dial;
+use aoc_common::visualize::{frame, Pixel, Rgb, Visualize};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+// Where the crucible is, which way it last moved, and how many blocks it has moved that way
+type Crucible = (Position, Option<Direction>, usize);
+
+// The heat loss of every block, with the crucible's path drawn over it
+struct Route<'a> {
+    graph: &'a Grid<usize>,
+    path: &'a [Crucible],
+}
+
+impl Visualize for Route<'_> {
+    fn draw(&self) -> Grid<Pixel> {
+        let mut pixels = self.graph.map(|&heat| {
+            let glyph = char::from_digit(heat as u32, 10).unwrap_or('?');
+            Pixel::new(glyph, Rgb::BLACK.mix(Rgb::ORANGE, heat as f64 / 9.0))
+        });
+        for &(point, direction, _) in self.path {
+            let glyph = match direction {
+                Some(Direction::Up) => '^',
+                Some(Direction::Down) => 'v',
+                Some(Direction::Left) => '<',
+                Some(Direction::Right) => '>',
+                None => '*',
+            };
+            pixels[point] = Pixel::new(glyph, Rgb::WHITE);
+        }
+        pixels
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+fn get_lowest_heat_path(
+    graph: &Grid<usize>,
+    start: Position,
+    end: Position,
+    dir_minimum: usize,
+    dir_limit: usize,
+) -> Option<usize> {
+    let moves_from = |&(point, direction, moves): &Crucible| {
+        Direction::ALL.into_iter().filter_map(move |dir| {
+            // if we are at opposite direction
+            // if we are at dir limit and going the same direction
+            // if its some direction other than the current but current is less than min moves
+            // SKIP
+            if direction == Some(dir.reverse())
+                || direction == Some(dir) && moves == dir_limit
+                || direction.is_some() && direction != Some(dir) && moves < dir_minimum
+            {
+                return None;
+            }
+
+            // if we are moving the same direction iterate the moves
+            let next_moves = if direction == Some(dir) { moves + 1 } else { 1 };
+
+            let next_point = graph.step(point, dir)?;
+            Some(((next_point, Some(dir), next_moves), graph[next_point]))
+        })
+    };
+
+    // the heat losses are single digits, which suits a bucket queue
+    let found = dial((start, None, 0), moves_from, |&(point, _, moves)| {
+        point == end && moves >= dir_minimum
+    })?;
+    // trace the path out from the start in about a hundred frames
+    let stride = found.path.len().div_ceil(100);
+    for end in (1..found.path.len())
+        .step_by(stride)
+        .chain([found.path.len()])
+    {
+        frame(&Route {
+            graph,
+            path: &found.path[..end],
+        });
+    }
+    Some(found.cost)
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let graph = deser(input)?;
+    get_lowest_heat_path(
+        &graph,
+        (0, 0),
+        (graph.height() - 1, graph.width() - 1),
+        4,
+        10,
+    )
+    .ok_or_else(|| no_path(input))
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let graph = deser(input)?;
+    get_lowest_heat_path(
+        &graph,
+        (0, 0),
+        (graph.height() - 1, graph.width() - 1),
+        0,
+        3,
+    )
+    .ok_or_else(|| no_path(input))
+}
+
+fn no_path(input: &str) -> ParseError {
+    ParseError::end(
+        input,
+        "no path to the bottom right keeps to the crucible's limits",
+    )
+}
+
+fn deser(input: &str) -> Result<Grid<usize>, ParseError> {
+    let graph = parse_phase(|| Grid::parse(input, |c| c.to_digit(10).map(|d| d as usize)))?;
+    if graph.width() == 0 {
+        return Err(ParseError::end(input, "expected a map of heat losses"));
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day17);
+    aoc_common::generated_tests!(Day17, [5, 12, 30]);
+
+    #[test]
+    fn test_no_path() {
+        // An ultra crucible has to move at least four blocks before it can stop
+        assert_eq!(part_1("1\n"), Ok(0));
+        assert!(part_2("1\n").is_err());
+        assert!(part_2("12\n34\n").is_err());
+    }
+}
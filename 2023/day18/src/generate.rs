@@ -0,0 +1,96 @@
+//! Random dig plans.
+
+use std::cmp::Ordering;
+
+use aoc_common::generate::{outline, region, Generator, Rng};
+
+/// The shape of a generated plan. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the grid the lagoon's shape is grown on, at least 1.
+    pub side: usize,
+    /// About how much of that grid the lagoon covers.
+    pub fill: f64,
+    /// The longest gap between neighbouring columns, or rows, of corners in part 1.
+    pub max_short: i64,
+    /// The same in part 2, where no edge can be longer than five hex digits.
+    pub max_long: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 100,
+            fill: 0.3,
+            max_short: 6,
+            max_long: 10_000,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Plans like `R 6 (#70c710)` that dig out the same simple loop twice: once with the short
+/// edges in the directions and sizes, and once stretched with the long edges in the colors.
+/// The loop never touches itself in either, and goes clockwise.
+///
+/// Panics if the long edges could need more than five hex digits.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let side = params.side.max(1);
+    assert!(
+        side as i64 * params.max_long < 1 << 20,
+        "a part 2 edge could be too long"
+    );
+    let shape = region(
+        rng,
+        side,
+        side,
+        (params.fill * (side * side) as f64) as usize,
+    );
+
+    // Where each column and row of the shape's corners ends up once stretched, with the gaps
+    // between them picked at random so no two edges are alike
+    let mut stretch = |max: i64| -> Vec<i64> {
+        let mut at = 0;
+        (0..=side)
+            .map(|_| {
+                let here = at;
+                at += rng.range(1..=max.max(1));
+                here
+            })
+            .collect()
+    };
+    let (short_x, short_y) = (stretch(params.max_short), stretch(params.max_short));
+    let (long_x, long_y) = (stretch(params.max_long), stretch(params.max_long));
+
+    let corners = outline(&shape);
+    let mut input = String::new();
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        let (direction, digit) = match (next.x.cmp(&corner.x), next.y.cmp(&corner.y)) {
+            (Ordering::Greater, _) => ('R', 0),
+            (_, Ordering::Greater) => ('D', 1),
+            (Ordering::Less, _) => ('L', 2),
+            _ => ('U', 3),
+        };
+        let length = |xs: &[i64], ys: &[i64]| {
+            let (from, to) = (corner.x as usize, next.x as usize);
+            let (top, bottom) = (corner.y as usize, next.y as usize);
+            (xs[to] - xs[from]).abs() + (ys[bottom] - ys[top]).abs()
+        };
+        let (short, long) = (length(&short_x, &short_y), length(&long_x, &long_y));
+        input.push_str(&format!("{direction} {short} (#{long:05x}{digit})\n"));
+    }
+    input
+}
@@ -0,0 +1,178 @@
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::parse::parse;
+use aoc_common::polygon::Polygon;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+#[derive(Debug)]
+struct Plan {
+    dir: Direction,
+    size: usize,
+}
+
+// Splits a line like `R 6 (#70c710)` into its direction, size and the colour's hex digits
+fn fields(l: &str) -> Result<(&str, &str, &str), ParseError> {
+    let mut split = l.split_ascii_whitespace();
+    let (Some(dir), Some(size), Some(color), None) =
+        (split.next(), split.next(), split.next(), split.next())
+    else {
+        return Err(ParseError::new(l, "expected `direction size (#colour)`"));
+    };
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|c| c.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| ParseError::new(color, "expected a colour like `(#70c710)`"))?;
+    Ok((dir, size, hex))
+}
+
+impl Plan {
+    fn from_line(l: &str) -> Result<Self, ParseError> {
+        // the colour is only checked, as part 1 doesn't use it
+        let (dir, size, _) = fields(l)?;
+        let dir = Some(dir)
+            .filter(|d| matches!(*d, "U" | "D" | "L" | "R"))
+            .and_then(|d| Direction::from_char(d.chars().next()?))
+            .ok_or_else(|| ParseError::new(dir, "expected U, D, L or R"))?;
+        let size = parse(size, "a size")?;
+        Ok(Plan { dir, size })
+    }
+
+    // Where digging this step from `start` ends up
+    fn end(&self, start: Point) -> Point {
+        start.step(self.dir, self.size as i64)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+fn deser_part_1(input: &str) -> Result<Polygon, ParseError> {
+    parse_phase(|| {
+        let plans: Vec<Plan> = input
+            .lines()
+            .map(Plan::from_line)
+            .collect::<Result<_, _>>()?;
+        trench(input, &plans)
+    })
+}
+
+fn deser_part_2(input: &str) -> Result<Polygon, ParseError> {
+    parse_phase(|| {
+        let plans: Vec<Plan> = input
+            .lines()
+            .map(|l| {
+                let (_, _, color) = fields(l)?;
+
+                let size = usize::from_str_radix(&color[0..5], 16).expect("checked for hex digits");
+                let dir = match &color[5..] {
+                    "0" => Direction::Right,
+                    "1" => Direction::Down,
+                    "2" => Direction::Left,
+                    "3" => Direction::Up,
+                    other => return Err(ParseError::new(other, "expected a direction digit 0-3")),
+                };
+
+                Ok(Plan { dir, size })
+            })
+            .collect::<Result<_, _>>()?;
+        trench(input, &plans)
+    })
+}
+
+// The trench dug by following `plans`, read in order from the lines of `input`. It has to come
+// back to where it started without crossing or touching itself.
+fn trench(input: &str, plans: &[Plan]) -> Result<Polygon, ParseError> {
+    // A step that digs nothing doesn't make an edge
+    let steps: Vec<(&str, &Plan)> = input
+        .lines()
+        .zip(plans)
+        .filter(|(_, plan)| plan.size > 0)
+        .collect();
+    let mut position = Point::ORIGIN;
+    let trench: Polygon = steps
+        .iter()
+        .map(|(_, plan)| {
+            position = plan.end(position);
+            position
+        })
+        .collect();
+    if position != Point::ORIGIN {
+        return Err(ParseError::end(
+            input,
+            "the trench doesn't come back to where it started",
+        ));
+    }
+    // Edge `i` runs from where step `i` ends to where the step after it does
+    if let Some((_, j)) = trench.self_intersection() {
+        let (line, _) = steps[(j + 1) % steps.len()];
+        return Err(ParseError::new(line, "the trench crosses itself here"));
+    }
+    Ok(trench)
+}
+
+// The cubic metres dug out: the trench itself and everything inside it
+fn lagoon_size(trench: &Polygon) -> usize {
+    trench.lattice_points() as usize
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let trench = deser_part_2(input)?;
+
+    Ok(lagoon_size(&trench))
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let trench = deser_part_1(input)?;
+
+    Ok(lagoon_size(&trench))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day18);
+    aoc_common::generated_tests!(Day18, [1, 5, 30]);
+
+    #[test]
+    fn test_trench_checks() {
+        let open = "R 2 (#000020)\nD 2 (#000021)\n";
+        let error = part_1(open).unwrap_err();
+        assert_eq!(
+            error.message,
+            "the trench doesn't come back to where it started"
+        );
+        assert!(part_2(open).is_err());
+
+        // A figure of eight, crossing at (1, 1)
+        let crossing = "R 1 (#70c710)\nD 2 (#70c710)\nR 1 (#70c710)\nU 1 (#70c710)\n\
+                        L 2 (#70c710)\nU 1 (#70c710)\n";
+        let error = part_1(crossing).unwrap_err();
+        assert_eq!(error.message, "the trench crosses itself here");
+        assert_eq!(error.text, "L 2 (#70c710)");
+    }
+}
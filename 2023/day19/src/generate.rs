@@ -0,0 +1,99 @@
+//! Random workflows and parts to sort with them.
+
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated system. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// About how many workflows there are, counting `in`.
+    pub workflows: usize,
+    pub parts: usize,
+    /// The most rules with conditions in a workflow, before the one without.
+    pub max_rules: usize,
+    /// The highest rating, with the lowest 1.
+    pub max_rating: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            workflows: 550,
+            parts: 200,
+            max_rules: 3,
+            max_rating: 4000,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(workflows: usize) -> Self {
+        Params {
+            workflows,
+            parts: workflows.div_ceil(3),
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Workflows like `px{a<2006:qkq,m>2090:A,rfg}` in no particular order, a blank line, then
+/// parts like `{x=787,m=2655,a=1222,s=2876}`. The workflows form a tree from `in`, so every
+/// part ends up accepted or rejected, and every workflow but `in` is sent to from exactly
+/// one rule.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let max = params.max_rating.max(2);
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut pending = vec!["in".to_string()];
+    let mut workflows = Vec::new();
+    while let Some(name) = pending.pop() {
+        let rules = rng.range(1..=params.max_rules.max(1));
+        let mut destination = |rng: &mut Rng| {
+            if names.len() < params.workflows && rng.chance(0.6) {
+                let name = new_name(rng, &names);
+                names.insert(name.clone());
+                pending.push(name.clone());
+                name
+            } else if rng.chance(0.5) {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            }
+        };
+        let mut steps: Vec<String> = (0..rules)
+            .map(|_| {
+                let category = rng.choose(&['x', 'm', 'a', 's']);
+                let operation = rng.choose(&['<', '>']);
+                let rating = rng.range(2..max);
+                format!("{category}{operation}{rating}:{}", destination(rng))
+            })
+            .collect();
+        steps.push(destination(rng));
+        workflows.push(format!("{name}{{{}}}", steps.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+
+    let mut input = workflows.join("\n");
+    input.push_str("\n\n");
+    for _ in 0..params.parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=max));
+        input.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+    input
+}
+
+// Two or three lowercase letters that aren't taken
+fn new_name(rng: &mut Rng, taken: &HashSet<String>) -> String {
+    loop {
+        let name: String = (0..rng.range(2..=3))
+            .map(|_| char::from(b'a' + rng.range(0..26_u8)))
+            .collect();
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::generate::Rng;
+use aoc_common::interval::Interval;
+use aoc_common::parse::{key_values, paragraphs, parse, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+#[cfg(test)]
+mod reference;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Accepted,
+    Rejected,
+}
+
+#[derive(Debug)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+    status: Option<Status>,
+}
+
+impl Part {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let mut part = Self {
+            x: 0,
+            m: 0,
+            a: 0,
+            s: 0,
+            status: None,
+        };
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|l| l.strip_suffix('}'))
+            .ok_or_else(|| {
+                ParseError::new(line, "expected a part like `{x=787,m=2655,a=1222,s=2876}`")
+            })?;
+        for (category, val) in key_values(ratings, ",", "=")? {
+            let val = parse(val, "a rating")?;
+            match category {
+                "x" => part.x = val,
+                "m" => part.m = val,
+                "a" => part.a = val,
+                "s" => part.s = val,
+                _ => return Err(ParseError::new(category, "expected x, m, a or s")),
+            }
+        }
+
+        Ok(part)
+    }
+
+    fn value(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    operation: char,
+    category: char,
+    size: usize,
+}
+
+// Each workflow's rules in order, with the workflow to send a matching part to
+type Rules<'a> = Vec<(Option<Rule>, &'a str)>;
+type Workflows<'a> = HashMap<&'a str, Rules<'a>>;
+
+// A workflow line like `px{a<2006:qkq,m>2090:A,rfg}`
+fn parse_workflow(line: &str) -> Result<(&str, Rules<'_>), ParseError> {
+    let (name, rules) = split_once(line, "{")?;
+    let rules = rules
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::new(line, "expected the rules to end with `}`"))?;
+    let last = rules.rsplit(',').next().unwrap_or(rules);
+    if last.contains(':') {
+        return Err(ParseError::new(
+            last,
+            "expected the last rule to send parts on without a condition",
+        ));
+    }
+    let rules = rules
+        .split(',')
+        .map(|rule| match rule.split_once(':') {
+            None => Ok((None, rule)),
+            Some((condition, dest)) => {
+                let mut chars = condition.chars();
+                let (Some(category @ ('x' | 'm' | 'a' | 's')), Some(operation @ ('<' | '>'))) =
+                    (chars.next(), chars.next())
+                else {
+                    return Err(ParseError::new(
+                        condition,
+                        "expected a condition like `a<2006`",
+                    ));
+                };
+                let size = parse(&condition[2..], "a rating")?;
+                let rule = Rule {
+                    category,
+                    operation,
+                    size,
+                };
+                Ok((Some(rule), dest))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((name, rules))
+}
+
+// Checks that no workflow reachable from `name` sends parts back to one they came through.
+// `path` holds the workflows on the way to `name`, and `done` the ones already checked.
+fn check_no_loop<'a>(
+    workflows: &Workflows<'a>,
+    name: &'a str,
+    path: &mut HashSet<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Result<(), ParseError> {
+    let Some((&name, rules)) = workflows.get_key_value(name) else {
+        return Ok(());
+    };
+    if done.contains(name) {
+        return Ok(());
+    }
+    if !path.insert(name) {
+        return Err(ParseError::new(name, "the workflows loop back to this one"));
+    }
+    for (_, dest) in rules {
+        check_no_loop(workflows, dest, path, done)?;
+    }
+    path.remove(name);
+    done.insert(name);
+    Ok(())
+}
+
+fn deserialize(input: &str) -> Result<(Workflows<'_>, Vec<Part>), ParseError> {
+    parse_phase(|| {
+        let mut paragraphs = paragraphs(input);
+        let (Some(workflows), Some(parts)) = (paragraphs.next(), paragraphs.next()) else {
+            return Err(ParseError::end(
+                input,
+                "expected a blank line between the workflows and the parts",
+            ));
+        };
+        if let Some(extra) = paragraphs.next() {
+            return Err(ParseError::new(extra, "expected nothing after the parts"));
+        }
+        let mut workflow_map = Workflows::new();
+        for line in workflows.lines() {
+            let (name, rules) = parse_workflow(line)?;
+            if workflow_map.insert(name, rules).is_some() {
+                return Err(ParseError::new(name, "duplicate workflow"));
+            }
+        }
+
+        if !workflow_map.contains_key("in") {
+            return Err(ParseError::end(workflows, "no `in` workflow to start from"));
+        }
+        for (_, dest) in workflow_map.values().flatten() {
+            if !matches!(*dest, "A" | "R") && !workflow_map.contains_key(dest) {
+                return Err(ParseError::new(dest, "unknown workflow"));
+            }
+        }
+        check_no_loop(
+            &workflow_map,
+            "in",
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+        )?;
+
+        let parts = parts
+            .lines()
+            .map(Part::from_line)
+            .collect::<Result<_, _>>()?;
+        Ok((workflow_map, parts))
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let (workflow_map, mut parts) = deserialize(input)?;
+
+    let mut total = 0;
+    for part in &mut parts {
+        // start workflow at in
+        let mut workflow = workflow_map.get("in").unwrap();
+        while part.status.is_none() {
+            for (rule, dest) in workflow {
+                // No rule we are directly at a destination
+                if rule.is_none() {
+                    match *dest {
+                        "A" => part.status = Some(Status::Accepted),
+                        "R" => part.status = Some(Status::Rejected),
+                        _ => workflow = workflow_map.get(dest).unwrap(),
+                    }
+                    break;
+                } else {
+                    let rule = rule.as_ref().unwrap();
+                    let value = match rule.category {
+                        'x' => part.x,
+                        'm' => part.m,
+                        'a' => part.a,
+                        's' => part.s,
+                        _ => unreachable!("Invalid category"),
+                    };
+                    match rule.operation {
+                        '<' => {
+                            if value < rule.size {
+                                match *dest {
+                                    "A" => part.status = Some(Status::Accepted),
+                                    "R" => part.status = Some(Status::Rejected),
+                                    _ => workflow = workflow_map.get(dest).unwrap(),
+                                }
+                                break;
+                            } else {
+                                continue;
+                            }
+                        }
+                        '>' => {
+                            if value > rule.size {
+                                match *dest {
+                                    "A" => part.status = Some(Status::Accepted),
+                                    "R" => part.status = Some(Status::Rejected),
+                                    _ => workflow = workflow_map.get(dest).unwrap(),
+                                }
+                                break;
+                            } else {
+                                continue;
+                            }
+                        }
+                        _ => unreachable!("Invalid operation"),
+                    }
+                }
+            }
+        }
+        if part.status == Some(Status::Accepted) {
+            total += part.value();
+        }
+    }
+
+    Ok(total)
+}
+
+// Where a category's ratings sit in the `[Interval; 4]` a range of parts is tracked as
+fn category_index(category: char) -> usize {
+    match category {
+        'x' => 0,
+        'm' => 1,
+        'a' => 2,
+        's' => 3,
+        _ => unreachable!("Invalid category"),
+    }
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let (workflow_map, _parts) = deserialize(input)?;
+
+    let mut total = 0;
+    let mut states = vec![("in", [Interval::new(1, 4001); 4])];
+
+    while let Some((destination, mut ratings)) = states.pop() {
+        match destination {
+            "A" => {
+                total += ratings.iter().map(Interval::len).product::<u64>();
+                continue;
+            }
+            "R" => continue,
+            _ => {}
+        }
+        for (rule, dest) in &workflow_map[destination] {
+            let Some(rule) = rule else {
+                states.push((dest, ratings));
+                break;
+            };
+            // Split off the ratings that match the rule, and carry the rest on to the next one
+            let i = category_index(rule.category);
+            let size = rule.size as i64;
+            let (matching, rest) = match rule.operation {
+                '<' => ratings[i].split_at(size),
+                '>' => {
+                    let (below, above) = ratings[i].split_at(size + 1);
+                    (above, below)
+                }
+                _ => unreachable!("Invalid operation"),
+            };
+            if !matching.is_empty() {
+                let mut matched = ratings;
+                matched[i] = matching;
+                states.push((dest, matched));
+            }
+            if rest.is_empty() {
+                break;
+            }
+            ratings[i] = rest;
+        }
+    }
+
+    Ok(total as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day19);
+    aoc_common::generated_tests!(Day19, [1, 10, 200]);
+
+    #[test]
+    fn test_last_rule_has_a_condition() {
+        let input = "in{x<5:A}\n\n{x=10,m=1,a=1,s=1}\n";
+        let error = part_1(input).unwrap_err();
+        assert_eq!(
+            error.message,
+            "expected the last rule to send parts on without a condition"
+        );
+        assert!(part_2(input).is_err());
+    }
+
+    #[test]
+    fn test_no_in_workflow() {
+        let input = "aa{A}\n\n{x=10,m=1,a=1,s=1}\n";
+        let error = part_1(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "no `in` workflow to start from");
+        assert_eq!(error.position, Some((1, 6)));
+    }
+
+    #[test]
+    fn test_duplicate_workflow() {
+        let input = "in{A}\nin{R}\n\n{x=10,m=1,a=1,s=1}\n";
+        let error = part_1(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "duplicate workflow");
+        assert_eq!(error.position, Some((2, 1)));
+    }
+
+    #[test]
+    fn test_workflows_loop() {
+        let input = "in{x<5:A,aa}\naa{in}\n\n{x=10,m=1,a=1,s=1}\n";
+        let error = part_1(input).unwrap_err();
+        assert_eq!(error.message, "the workflows loop back to this one");
+        assert!(part_2(input).is_err());
+        // Two ways to reach the same workflow aren't a loop
+        let input = "in{x<5:aa,bb}\nbb{aa}\naa{A}\n\n{x=10,m=1,a=1,s=1}\n";
+        assert_eq!(part_1(input), Ok(13));
+    }
+
+    #[test]
+    fn test_against_reference() {
+        let generate = |rng: &mut Rng| {
+            let params = generate::Params {
+                workflows: rng.range(1..=8),
+                parts: 1,
+                max_rules: 3,
+                max_rating: 8,
+            };
+            generate::generate(rng, &params)
+        };
+        aoc_common::differential::assert_agree(200, generate, part_2, |input| {
+            Ok(reference::part_2(input))
+        });
+    }
+}
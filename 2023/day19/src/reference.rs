@@ -0,0 +1,75 @@
+//! Slow but obviously correct answers, to check the splitting of rating ranges against.
+
+use std::collections::HashMap;
+
+/// Sends every combination of ratings through the workflows one by one.
+///
+/// Ratings above every number in the rules all go the same way, so only one of them is tried,
+/// counting for all of them up to 4000. Panics if the input isn't one the puzzle could give,
+/// with parts and with workflows that send every part to one that exists and on to `A` or `R`.
+pub fn part_2(input: &str) -> usize {
+    let (workflows, parts) = input.split_once("\n\n").unwrap();
+    assert!(!parts.trim().is_empty(), "no parts");
+    let workflows: HashMap<&str, Vec<&str>> = workflows
+        .lines()
+        .map(|line| {
+            let (name, rules) = line.strip_suffix('}').unwrap().split_once('{').unwrap();
+            (name, rules.split(',').collect())
+        })
+        .collect();
+    for rule in workflows.values().flatten() {
+        let destination = rule
+            .split_once(':')
+            .map_or(*rule, |(_, destination)| destination);
+        assert!(matches!(destination, "A" | "R") || workflows.contains_key(destination));
+    }
+    let above = workflows
+        .values()
+        .flatten()
+        .filter_map(|rule| rule.split_once(':'))
+        .map(|(condition, _)| condition[2..].parse::<usize>().unwrap() + 1)
+        .max()
+        .unwrap_or(1);
+    assert!(above <= 4000);
+    let count = |rating| if rating == above { 4001 - above } else { 1 };
+
+    let mut total = 0;
+    for x in 1..=above {
+        for m in 1..=above {
+            for a in 1..=above {
+                for s in 1..=above {
+                    if accepted(&workflows, [x, m, a, s]) {
+                        total += count(x) * count(m) * count(a) * count(s);
+                    }
+                }
+            }
+        }
+    }
+    total
+}
+
+fn accepted(workflows: &HashMap<&str, Vec<&str>>, ratings: [usize; 4]) -> bool {
+    let mut workflow = "in";
+    for _ in 0..=workflows.len() {
+        for rule in &workflows[workflow] {
+            let Some((condition, destination)) = rule.split_once(':') else {
+                workflow = rule;
+                break;
+            };
+            let rating = ratings["xmas".find(&condition[..1]).unwrap()];
+            let number = condition[2..].parse().unwrap();
+            if condition.as_bytes()[1] == b'<' && rating < number
+                || condition.as_bytes()[1] == b'>' && rating > number
+            {
+                workflow = destination;
+                break;
+            }
+        }
+        match workflow {
+            "A" => return true,
+            "R" => return false,
+            _ => {}
+        }
+    }
+    panic!("the workflows loop");
+}
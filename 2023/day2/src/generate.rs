@@ -0,0 +1,56 @@
+//! Random records of games of cubes.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated record. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub games: usize,
+    pub max_draws: usize,
+    /// The most cubes of one color in a draw.
+    pub max_cubes: u8,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            games: 100,
+            max_draws: 6,
+            max_cubes: 20,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(games: usize) -> Self {
+        Params {
+            games,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Games numbered from 1, each with draws of one to three colors, like
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for game in 1..=params.games {
+        let draws: Vec<String> = (0..rng.range(1..=params.max_draws.max(1)))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors[..rng.range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=params.max_cubes.max(1))))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {game}: {}\n", draws.join("; ")));
+    }
+    input
+}
@@ -1,4 +1,9 @@
-const INPUT: &str = include_str!("../../data/input.txt");
+use aoc_common::generate::Rng;
+use aoc_common::parse::{parse, split_once};
+use aoc_common::{Answer, ParseError, PartResult, Solution};
+use tracing::trace;
+
+pub mod generate;
 
 #[derive(Debug, PartialEq, Eq)]
 struct Game {
@@ -15,9 +20,8 @@ impl Game {
 
     pub fn find_game_power(&self) -> u64 {
         let min_draw = self.find_min_draw();
-        // println!("{:#?}", min_draw);
         let power = min_draw.blue as u64 * min_draw.green as u64 * min_draw.red as u64;
-        // println!("Game power: {}", power);
+        trace!(game = self.game_id, ?min_draw, power, "game power");
         power
     }
 
@@ -51,49 +55,68 @@ impl Draw {
     }
 }
 
-fn main() {
-    // let solved = solve(INPUT);
-    let solved = solve_part_2(INPUT);
-    println!("{solved}");
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(solve_part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(solve_part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
 }
 
-fn solve_part_2(input: &str) -> u64 {
+pub fn solve_part_2(input: &str) -> Result<u64, ParseError> {
     let mut sum = 0;
-    let games = parse_games(input);
-    // println!("{:#?}", games);
+    let games = parse_games(input)?;
+    trace!(?games);
     for game in games {
         sum += game.find_game_power();
     }
-    sum
+    Ok(sum)
 }
 
-fn solve_part_1(input: &str) -> u16 {
+pub fn solve_part_1(input: &str) -> Result<u16, ParseError> {
     let mut sum = 0;
-    let games = parse_games(input);
+    let games = parse_games(input)?;
     for game in games {
         if game.is_game_possible(12, 14, 13) {
             sum += game.game_id;
         }
     }
-    sum
+    Ok(sum)
 }
 
-fn parse_games(input: &str) -> Vec<Game> {
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
     input
         .lines()
-        .map(|line| Game {
-            game_id: get_game_id(line),
-            draws: get_game_draws(line),
+        .map(|line| {
+            Ok(Game {
+                game_id: get_game_id(line)?,
+                draws: get_game_draws(line)?,
+            })
         })
         .collect()
 }
 
 // for a given game, get the draws
-fn get_game_draws(line: &str) -> Vec<Draw> {
+fn get_game_draws(line: &str) -> Result<Vec<Draw>, ParseError> {
     let mut draws: Vec<Draw> = Vec::new();
 
-    let split: Vec<&str> = line.split(':').collect();
-    let game: &str = split[1];
+    let (_, game) = split_once(line, ":")?;
 
     let draw_splits: Vec<&str> = game.split(';').collect();
 
@@ -104,11 +127,11 @@ fn get_game_draws(line: &str) -> Vec<Draw> {
 
         for cube in cubes {
             let cube = cube.trim();
-            let cube_split: Vec<&str> = cube.split(' ').collect();
+            let (count, colour) = split_once(cube, " ")?;
 
-            let count: u8 = cube_split[0].parse().unwrap();
+            let count: u8 = parse(count, "a cube count")?;
 
-            match cube_split[1] {
+            match colour {
                 "green" => {
                     draw.green += count;
                 }
@@ -118,24 +141,29 @@ fn get_game_draws(line: &str) -> Vec<Draw> {
                 "blue" => {
                     draw.blue += count;
                 }
-                _ => unreachable!(),
+                _ => return Err(ParseError::new(colour, "expected red, green or blue")),
             };
         }
         draws.push(draw);
     }
-    draws
+    Ok(draws)
 }
 
-fn get_game_id(line: &str) -> u16 {
-    let line = line.replace("Game ", "");
-    let game_id_str = line.split(':').next().unwrap();
-    game_id_str.parse().expect("will be a number at this spot")
+fn get_game_id(line: &str) -> Result<u16, ParseError> {
+    let line = line
+        .strip_prefix("Game ")
+        .ok_or_else(|| ParseError::new(line, "expected \"Game \""))?;
+    let (game_id_str, _) = split_once(line, ":")?;
+    parse(game_id_str, "a game id")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aoc_common::example_tests!(Day2);
+    aoc_common::generated_tests!(Day2, [1, 20, 100]);
+
     #[test]
     fn test_find_game_power() {
         let game = Game {
@@ -173,7 +201,13 @@ mod tests {
             draws: vec![Draw::new(3, 4, 0), Draw::new(6, 1, 2), Draw::new(0, 0, 2)],
         }];
 
-        assert_eq!(parse_games(input), expected)
+        assert_eq!(parse_games(input), Ok(expected));
+        assert_eq!(
+            parse_games("Game 1: 3 blue, 4 purple")
+                .unwrap_err()
+                .to_string(),
+            "expected red, green or blue, got \"purple\""
+        );
     }
 
     #[test]
@@ -182,9 +216,9 @@ mod tests {
         let test2 = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
         let test3 = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
-        assert_eq!(get_game_id(test1), 1);
-        assert_eq!(get_game_id(test2), 2);
-        assert_eq!(get_game_id(test3), 5);
+        assert_eq!(get_game_id(test1), Ok(1));
+        assert_eq!(get_game_id(test2), Ok(2));
+        assert_eq!(get_game_id(test3), Ok(5));
     }
 
     #[test]
@@ -195,17 +229,25 @@ mod tests {
 
         assert_eq!(
             get_game_draws(test1),
-            vec![Draw::new(3, 4, 0), Draw::new(6, 1, 2), Draw::new(0, 0, 2)]
+            Ok(vec![
+                Draw::new(3, 4, 0),
+                Draw::new(6, 1, 2),
+                Draw::new(0, 0, 2)
+            ])
         );
 
         assert_eq!(
             get_game_draws(test2),
-            vec![Draw::new(1, 0, 2), Draw::new(4, 1, 3), Draw::new(1, 0, 1)]
+            Ok(vec![
+                Draw::new(1, 0, 2),
+                Draw::new(4, 1, 3),
+                Draw::new(1, 0, 1)
+            ])
         );
 
         assert_eq!(
             get_game_draws(test3),
-            vec![Draw::new(1, 6, 3), Draw::new(2, 1, 2)]
+            Ok(vec![Draw::new(1, 6, 3), Draw::new(2, 1, 2)])
         );
     }
 }
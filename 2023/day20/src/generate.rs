@@ -0,0 +1,123 @@
+//! Random module configurations, built like the real ones out of counters.
+
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated configuration. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The flip-flops in each counter, from 2 to 16. A counter with `bits` flip-flops counts
+    /// up to a period between `2^(bits - 1)` and `2^bits`.
+    pub bits: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { bits: 12 }
+    }
+}
+
+impl Generator for Params {
+    fn sized(bits: usize) -> Self {
+        Params {
+            bits: bits.clamp(2, 16) as u32,
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A configuration where `rx` first gets a low pulse on the press that is the least common
+/// multiple of four counters' periods.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let periods = periods(rng, params);
+    network(rng, params, &periods)
+}
+
+/// The periods of the four counters: odd numbers with the top of `bits` bits set.
+pub fn periods(rng: &mut Rng, params: &Params) -> [u64; 4] {
+    let top = 1 << (params.bits - 1);
+    [(); 4].map(|_| top | rng.range(0..top) | 1)
+}
+
+/// The configuration for counters with the given periods.
+///
+/// Each counter is a chain of flip-flops counting button presses in binary, lowest bit
+/// first, and a conjunction hub. The flip-flops for the set bits of the period feed the hub,
+/// so it sends a low pulse once the count reaches the period. That pulse goes to the
+/// flip-flops for the clear bits and the lowest bit, adding up to the next power of 2 and
+/// resetting the count to 0, and through an inverter to a conjunction feeding `rx` as a high
+/// pulse. That conjunction sends `rx` a low pulse once all four inverters pulse high on the same
+/// press.
+pub fn network(rng: &mut Rng, params: &Params, periods: &[u64; 4]) -> String {
+    let mut taken: HashSet<String> = ["broadcaster", "rx"].map(String::from).into();
+    let mut new_name = |rng: &mut Rng| loop {
+        let name: String = (0..2)
+            .map(|_| char::from(b'a' + rng.range(0..26_u8)))
+            .collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    let feeder = new_name(rng);
+    for &period in periods {
+        let inverter = new_name(rng);
+        let bits: Vec<String> = (0..params.bits).map(|_| new_name(rng)).collect();
+        let hub = new_name(rng);
+        let mut hub_outputs = vec![bits[0].clone()];
+        for (i, flip_flop) in bits.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = bits.get(i + 1) {
+                outputs.push(next.clone());
+            }
+            if period & 1 << i != 0 {
+                outputs.push(hub.clone());
+            } else {
+                hub_outputs.push(flip_flop.clone());
+            }
+            rng.shuffle(&mut outputs);
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        hub_outputs.push(inverter.clone());
+        rng.shuffle(&mut hub_outputs);
+        lines.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {feeder}"));
+        starts.push(bits[0].clone());
+    }
+    rng.shuffle(&mut starts);
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.push(format!("&{feeder} -> rx"));
+    rng.shuffle(&mut lines);
+
+    let mut input = lines.join("\n");
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_2;
+    use aoc_common::math::lcm_all;
+
+    #[test]
+    fn test_rx_waits_for_every_counter() {
+        for (seed, bits) in [(0, 2), (1, 5), (2, 8), (3, 12)] {
+            let params = Params::sized(bits);
+            let mut rng = Rng::new(seed);
+            let periods = periods(&mut rng, &params);
+            let input = network(&mut rng, &params, &periods);
+            assert_eq!(
+                part_2(&input).unwrap() as u64,
+                lcm_all(periods).unwrap(),
+                "periods {periods:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use aoc_common::generate::Rng;
+use aoc_common::math::{crt, Congruence};
+use aoc_common::parse::adjacency;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::{debug, trace};
+
+pub mod generate;
+#[cfg(test)]
+mod reference;
+
+trait Module: fmt::Debug {
+    fn pulse(&mut self, received: (String, PulseKind)) -> Vec<(String, PulseKind)>;
+    fn outputs(&self) -> Option<&[String]>;
+    fn inputs(&mut self) -> Option<&mut Vec<(String, PulseKind)>>;
+    fn counts(&self) -> (usize, usize);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+enum PulseKind {
+    #[default]
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Default)]
+enum ModuleKind {
+    FlipFlop,    // %
+    Conjunction, // &
+    #[default]
+    Broadcast, // broadcaster
+}
+
+#[derive(Debug, Clone, Default)]
+struct PulseCount {
+    low: usize,
+    high: usize,
+}
+
+#[derive(Debug)]
+struct Conjunction {
+    inputs: Vec<(String, PulseKind)>,
+    outputs: Vec<String>,
+    count: PulseCount,
+}
+
+impl Module for Conjunction {
+    fn pulse(&mut self, received: (String, PulseKind)) -> Vec<(String, PulseKind)> {
+        match received.1 {
+            PulseKind::Low => self.count.low += 1,
+            PulseKind::High => self.count.high += 1,
+        }
+
+        let matched = self
+            .inputs
+            .iter_mut()
+            .find(|input| input.0 == received.0)
+            .unwrap();
+        matched.1 = received.1;
+
+        let kind = match self.inputs.iter().all(|(_, p)| p == &PulseKind::High) {
+            true => Some(PulseKind::Low),
+            false => Some(PulseKind::High),
+        };
+
+        self.outputs
+            .iter()
+            .map(|o| (o.clone(), kind.as_ref().unwrap().clone()))
+            .collect()
+    }
+    fn outputs(&self) -> Option<&[String]> {
+        Some(&self.outputs)
+    }
+    fn inputs(&mut self) -> Option<&mut Vec<(String, PulseKind)>> {
+        Some(&mut self.inputs)
+    }
+    fn counts(&self) -> (usize, usize) {
+        (self.count.low, self.count.high)
+    }
+}
+
+#[derive(Debug)]
+struct FlipFlop {
+    on: bool,
+    count: PulseCount,
+    outputs: Vec<String>,
+}
+
+impl Module for FlipFlop {
+    fn pulse(&mut self, received: (String, PulseKind)) -> Vec<(String, PulseKind)> {
+        match received.1 {
+            PulseKind::Low => {
+                self.count.low += 1;
+                let pulse = match self.on {
+                    true => Some(PulseKind::Low),
+                    false => Some(PulseKind::High),
+                };
+                self.on = !self.on;
+                self.outputs
+                    .iter()
+                    .map(|o| (o.clone(), pulse.as_ref().unwrap().clone()))
+                    .collect()
+            }
+            PulseKind::High => {
+                self.count.high += 1;
+                vec![]
+            }
+        }
+    }
+    fn outputs(&self) -> Option<&[String]> {
+        Some(&self.outputs)
+    }
+    fn inputs(&mut self) -> Option<&mut Vec<(String, PulseKind)>> {
+        None
+    }
+    fn counts(&self) -> (usize, usize) {
+        (self.count.low, self.count.high)
+    }
+}
+
+#[derive(Debug)]
+struct Broadcast {
+    count: PulseCount,
+    outputs: Vec<String>,
+}
+
+impl Module for Broadcast {
+    fn pulse(&mut self, received: (String, PulseKind)) -> Vec<(String, PulseKind)> {
+        match received.1 {
+            PulseKind::Low => self.count.low += 1,
+            PulseKind::High => self.count.high += 1,
+        }
+        self.outputs
+            .iter()
+            .map(|o| (o.clone(), PulseKind::Low))
+            .collect()
+    }
+    fn outputs(&self) -> Option<&[String]> {
+        Some(&self.outputs)
+    }
+    fn inputs(&mut self) -> Option<&mut Vec<(String, PulseKind)>> {
+        None
+    }
+    fn counts(&self) -> (usize, usize) {
+        (self.count.low, self.count.high)
+    }
+}
+
+#[derive(Debug)]
+struct End {
+    count: PulseCount,
+    inputs: Vec<(String, PulseKind)>,
+}
+
+impl Module for End {
+    fn pulse(&mut self, received: (String, PulseKind)) -> Vec<(String, PulseKind)> {
+        match received.1 {
+            PulseKind::Low => self.count.low += 1,
+            PulseKind::High => self.count.high += 1,
+        }
+        vec![]
+    }
+    fn outputs(&self) -> Option<&[String]> {
+        None
+    }
+    fn inputs(&mut self) -> Option<&mut Vec<(String, PulseKind)>> {
+        Some(&mut self.inputs)
+    }
+    fn counts(&self) -> (usize, usize) {
+        (self.count.low, self.count.high)
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+// Presses to give up after, enough for every input to the module feeding rx to pulse high twice
+// if they are counters of up to 16 bits
+const MAX_PRESSES: usize = 1 << 18;
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    let mut modules = deserialize(input)?;
+
+    // rx is fed by a single conjunction, which sends it a low pulse once all of its inputs have
+    // last sent it high ones, so find when each of them pulses high
+    let mut feeders = modules.iter_mut().filter(|(_, module)| {
+        module
+            .outputs()
+            .is_some_and(|outputs| outputs.iter().any(|output| output == "rx"))
+    });
+    let (Some((feeder, module)), None) = (feeders.next(), feeders.next()) else {
+        return Err(ParseError::end(
+            input,
+            "expected one module sending to `rx`",
+        ));
+    };
+    let feeder = feeder.clone();
+    let inputs: Vec<String> = match module.inputs() {
+        Some(inputs) if !inputs.is_empty() => inputs.iter().map(|(name, _)| name.clone()).collect(),
+        _ => {
+            return Err(ParseError::end(
+                input,
+                format!(
+                    "expected `{feeder}`, which sends to `rx`, to be a conjunction with inputs"
+                ),
+            ))
+        }
+    };
+
+    let mut cycles: Vec<Vec<usize>> = vec![vec![]; inputs.len()];
+    for idx in 0..MAX_PRESSES {
+        if cycles.iter().all(|c| c.len() >= 2) {
+            // we have enough to calculate the lcm
+            break;
+        }
+
+        let broadcast = modules.get_mut("broadcaster").unwrap();
+        let mut states = VecDeque::new();
+
+        broadcast
+            .pulse((String::from("button"), PulseKind::Low))
+            .iter()
+            .for_each(|(name, kind)| {
+                states.push_front(("broadcaster".to_string(), name.clone(), kind.clone()));
+            });
+
+        while let Some((from, to, kind)) = states.pop_back() {
+            if to == feeder && kind == PulseKind::High {
+                debug!(press = idx + 1, input = %from, "high pulse into {feeder}");
+                let cycle = &mut cycles[inputs.iter().position(|i| *i == from).unwrap()];
+                // only the press matters, not how many times it pulsed on it
+                if cycle.last() != Some(&idx) {
+                    cycle.push(idx);
+                }
+            }
+
+            trace!(%from, %to, ?kind, "pulse");
+            let module = modules.get_mut(&to).unwrap();
+            let outputs = module.pulse((from.clone(), kind.clone()));
+            outputs.iter().for_each(|output| {
+                states.push_front((to.clone(), output.0.clone(), output.1.clone()));
+            });
+        }
+    }
+    if cycles.iter().any(|c| c.len() < 2) {
+        return Err(ParseError::end(
+            input,
+            format!(
+                "the inputs to `{feeder}` didn't all pulse high twice in {MAX_PRESSES} presses"
+            ),
+        ));
+    }
+
+    // Each input first pulses high on some press and then every `period` presses after, so rx
+    // gets its low pulse on the first press that lines all of them up
+    let congruences = cycles.iter().map(|cycle| {
+        let period = (cycle[1] - cycle[0]) as i64;
+        Congruence::new(cycle[0] as i64 + 1, period)
+    });
+    let first = cycles.iter().map(|cycle| cycle[0] + 1).max().unwrap_or(0);
+    let presses = crt(congruences)
+        .ok_or_else(|| {
+            ParseError::end(
+                input,
+                format!("the inputs to `{feeder}` never pulse high on the same press"),
+            )
+        })?
+        .first_from(first as i64);
+    debug!(?cycles, presses, "lined up the inputs to {feeder}");
+
+    Ok(presses as usize)
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let mut modules = deserialize(input)?;
+
+    for _ in 0..1000 {
+        let broadcast = modules.get_mut("broadcaster").unwrap();
+        let mut states = VecDeque::new();
+
+        broadcast
+            .pulse((String::from("button"), PulseKind::Low))
+            .iter()
+            .for_each(|(name, kind)| {
+                states.push_front(("broadcaster".to_string(), name.clone(), kind.clone()));
+            });
+
+        while let Some((from, to, kind)) = states.pop_back() {
+            trace!(%from, %to, ?kind, "pulse");
+            let module = modules.get_mut(&to).unwrap();
+            let outputs = module.pulse((from.clone(), kind.clone()));
+            outputs.iter().for_each(|output| {
+                states.push_front((to.clone(), output.0.clone(), output.1.clone()));
+            });
+        }
+    }
+
+    let mut totals = (0_usize, 0_usize);
+    for module in modules.values() {
+        let counts = module.counts();
+        totals.0 += counts.0;
+        totals.1 += counts.1;
+    }
+    debug!(low = totals.0, high = totals.1, "pulses after 1000 presses");
+
+    Ok(totals.0 * totals.1)
+}
+
+// Builds every module from the configuration and tells each conjunction its inputs.
+fn deserialize(input: &str) -> Result<HashMap<String, Box<dyn Module>>, ParseError> {
+    parse_phase(|| {
+        let mut in_out = vec![];
+        let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
+
+        for l in input.lines() {
+            let (input, outputs) = adjacency(l, " -> ", ",")?;
+            let (kind, name) = if let Some(name) = input.strip_prefix('%') {
+                (ModuleKind::FlipFlop, name)
+            } else if let Some(name) = input.strip_prefix('&') {
+                (ModuleKind::Conjunction, name)
+            } else if input == "broadcaster" {
+                (ModuleKind::Broadcast, input)
+            } else {
+                return Err(ParseError::new(
+                    input,
+                    "expected `broadcaster` or a module name after `%` or `&`",
+                ));
+            };
+            if name.is_empty() {
+                return Err(ParseError::new(input, "expected a module name"));
+            }
+            let outputs: Vec<String> = outputs.into_iter().map(String::from).collect();
+
+            let module: Box<dyn Module> = match kind {
+                ModuleKind::FlipFlop => Box::new(FlipFlop {
+                    on: false,
+                    count: PulseCount::default(),
+                    outputs,
+                }),
+                ModuleKind::Conjunction => Box::new(Conjunction {
+                    inputs: Vec::new(),
+                    outputs,
+                    count: PulseCount::default(),
+                }),
+                ModuleKind::Broadcast => Box::new(Broadcast {
+                    count: PulseCount::default(),
+                    outputs,
+                }),
+            };
+
+            in_out.push((name.to_string(), module.outputs().unwrap().to_vec()));
+            if modules.insert(name.to_string(), module).is_some() {
+                return Err(ParseError::new(name, "duplicate module"));
+            }
+        }
+        if !modules.contains_key("broadcaster") {
+            return Err(ParseError::end(input, "no `broadcaster` module"));
+        }
+
+        for (name, outputs) in in_out {
+            for output in outputs {
+                let output = modules.entry(output).or_insert(Box::new(End {
+                    count: PulseCount::default(),
+                    inputs: Vec::new(),
+                }));
+                if output.inputs().is_none() {
+                    continue;
+                }
+                output
+                    .inputs()
+                    .unwrap()
+                    .push((name.clone(), PulseKind::Low));
+            }
+        }
+        Ok(modules)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::Generator;
+
+    aoc_common::example_tests!(Day20);
+    aoc_common::generated_tests!(Day20, [2, 6, 12]);
+
+    #[test]
+    fn test_part_2_needs_a_feeder() {
+        let no_rx = "broadcaster -> a\n%a -> b\n&b -> output\n";
+        assert!(part_2(no_rx).is_err());
+        let flip_flop = "broadcaster -> a\n%a -> rx\n";
+        assert!(part_2(flip_flop).is_err());
+        // The broadcaster only ever pulses low
+        let never_high = "broadcaster -> a\n&a -> rx\n";
+        assert!(part_2(never_high).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_module() {
+        let input = "broadcaster -> a\n%a -> b\n&a -> b\n";
+        let error = deserialize(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "duplicate module");
+        assert_eq!(error.position, Some((3, 2)));
+    }
+
+    #[test]
+    fn test_against_reference() {
+        let generate = |rng: &mut Rng| generate::Params::sized(rng.range(2..=4)).generate(rng);
+        aoc_common::differential::assert_agree(50, generate, part_2, |input| {
+            Ok(reference::part_2(input))
+        });
+    }
+}
@@ -0,0 +1,93 @@
+//! Slow but obviously correct answers, to check the least common multiple of the counters'
+//! periods against.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// More than networks of counters up to 4 bits need, whose periods' least common multiple is at
+// most that of 9, 11, 13 and 15, 6435
+const PRESSES: usize = 1 << 13;
+
+/// Presses the button until `rx` gets a low pulse.
+///
+/// Panics unless the network is wired as part 2 expects, with `rx` fed only by a conjunction
+/// and each of that conjunction's inputs pulsing it high on two presses by the time `rx` gets
+/// its pulse or within `PRESSES`, so part 2 can find their periods.
+pub fn part_2(input: &str) -> usize {
+    let mut kinds = HashMap::new();
+    let mut outputs: HashMap<&str, Vec<&str>> = HashMap::new();
+    for line in input.lines() {
+        let (module, to) = line.split_once(" -> ").unwrap();
+        let (kind, name) = match module.split_at(1) {
+            (kind @ ("%" | "&"), name) => (kind, name),
+            _ => ("broadcaster", module),
+        };
+        kinds.insert(name, kind);
+        outputs.insert(name, to.split(", ").collect());
+    }
+    // The last pulse each module got from each of its inputs, true for high
+    let mut memory: HashMap<&str, HashMap<&str, bool>> = HashMap::new();
+    for (&name, to) in &outputs {
+        for &next in to {
+            memory.entry(next).or_default().insert(name, false);
+        }
+    }
+    let inputs = |name| {
+        let mut inputs: Vec<&str> = memory
+            .get(name)
+            .into_iter()
+            .flat_map(|m| m.keys().copied())
+            .collect();
+        inputs.sort_unstable();
+        inputs
+    };
+    let [feeder] = inputs("rx")[..] else {
+        panic!("rx has more or less than one input");
+    };
+    assert_eq!(kinds.get(feeder), Some(&"&"));
+    let feeder_inputs = inputs(feeder).len();
+
+    let mut on = HashSet::new();
+    // The presses each input to the feeder pulsed it high on
+    let mut highs: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut first = None;
+    for press in 1..=PRESSES {
+        let mut pulses = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, high)) = pulses.pop_front() {
+            if to == "rx" && !high {
+                first.get_or_insert(press);
+            }
+            if to == feeder && high {
+                let presses = highs.entry(from).or_default();
+                if presses.last() != Some(&press) {
+                    presses.push(press);
+                }
+            }
+            let send = match kinds.get(to) {
+                None => continue,
+                Some(&"broadcaster") => high,
+                Some(&"%") if high => continue,
+                Some(&"%") => {
+                    let now_on = !on.remove(to);
+                    if now_on {
+                        on.insert(to);
+                    }
+                    now_on
+                }
+                Some(_) => {
+                    let last = memory.get_mut(to).unwrap();
+                    last.insert(from, high);
+                    !last.values().all(|&high| high)
+                }
+            };
+            for &next in &outputs[to] {
+                pulses.push_back((to, next, send));
+            }
+        }
+        if let Some(first) = first {
+            if highs.len() == feeder_inputs && highs.values().all(|presses| presses.len() >= 2) {
+                return first;
+            }
+        }
+    }
+    panic!("rx got no low pulse, or {feeder}'s inputs no two high ones, in {PRESSES} presses");
+}
@@ -0,0 +1,58 @@
+//! Random gardens.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated garden. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the garden, made odd so the start can be in the middle.
+    pub side: usize,
+    /// The chance of each plot being a rock.
+    pub rocks: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 131,
+            rocks: 0.12,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square garden with the start in the middle. As in the real inputs, the start's row and
+/// column and the edges of the garden have no rocks.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let side = params.side | 1;
+    let middle = side / 2;
+    let mut input = String::new();
+    for row in 0..side {
+        for column in 0..side {
+            let clear = [row, column]
+                .iter()
+                .any(|&x| x == 0 || x == middle || x == side - 1);
+            input.push(if row == middle && column == middle {
+                'S'
+            } else if !clear && rng.chance(params.rocks) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
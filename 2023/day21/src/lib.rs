@@ -0,0 +1,82 @@
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::search::bfs_distances;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum SpotKind {
+    Start,
+    Garden,
+    Rock,
+}
+
+impl SpotKind {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'S' => Some(Self::Start),
+            '#' => Some(Self::Rock),
+            '.' => Some(Self::Garden),
+            _ => None,
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input, 64).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1(input: &str, max_steps: usize) -> Result<usize, ParseError> {
+    let garden = deser(input)?;
+
+    let start = garden
+        .find(|&kind| kind == SpotKind::Start)
+        .ok_or_else(|| ParseError::end(input, "no start tile `S` in the garden"))?;
+
+    let distances = bfs_distances(start, |&position| {
+        garden
+            .neighbors(position)
+            .filter(|&next| garden[next] != SpotKind::Rock)
+            .collect::<Vec<_>>()
+    });
+
+    // Stepping back and forth, a plot reached in fewer steps can be reached again every other step
+    Ok(distances
+        .values()
+        .filter(|&&steps| steps <= max_steps && steps % 2 == max_steps % 2)
+        .count())
+}
+
+fn deser(input: &str) -> Result<Grid<SpotKind>, ParseError> {
+    parse_phase(|| Grid::parse(input, SpotKind::from_char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example_input;
+
+    aoc_common::generated_tests!(Day21, [1, 10, 40]);
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part_1(&example_input!("example.txt"), 6), Ok(16))
+    }
+}
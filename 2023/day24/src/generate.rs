@@ -0,0 +1,72 @@
+//! Random hailstorms that a single rock can pass through.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated hailstorm. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub hailstones: usize,
+    /// The most a velocity can be along each axis.
+    pub max_speed: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            hailstones: 300,
+            max_speed: 300,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(hailstones: usize) -> Self {
+        Params {
+            hailstones,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+// The real positions are around the test area of part 1
+const POSITIONS: std::ops::Range<i64> = 100_000_000_000_000..500_000_000_000_000;
+
+/// Hailstones at positive positions, one per line as `px, py, pz @ vx, vy, vz`. A rock thrown
+/// from some position with some velocity, both whole numbers, hits every one of them at a
+/// different whole time.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let axis = |rng: &mut Rng| {
+        (
+            rng.range(200_000_000_000_000..400_000_000_000_000),
+            rng.range(-params.max_speed..=params.max_speed),
+        )
+    };
+    let rock = [(); 3].map(|_| axis(rng));
+
+    let mut times = Vec::new();
+    let mut input = String::new();
+    while times.len() < params.hailstones {
+        // The hailstone is where the rock will be at `time`, as far back along its own
+        // velocity as the rock has come
+        let time: i64 = rng.range(10_000_000_000..500_000_000_000);
+        let velocity = [(); 3].map(|_| rng.range(-params.max_speed..=params.max_speed));
+        let position: [i64; 3] =
+            std::array::from_fn(|i| rock[i].0 + (rock[i].1 - velocity[i]) * time);
+        if times.contains(&time)
+            || velocity.iter().zip(&rock).all(|(&v, &(_, rock))| v == rock)
+            || !position.iter().all(|p| POSITIONS.contains(p))
+        {
+            continue;
+        }
+        times.push(time);
+        input.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        ));
+    }
+    input
+}
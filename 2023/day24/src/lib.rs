@@ -1,20 +1,34 @@
-use std::time::Instant;
+use aoc_common::generate::Rng;
+use aoc_common::parse::{parse, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
 
-const INPUT: &str = include_str!("./input.txt");
+pub mod generate;
 
-fn main() {
-    let s = Instant::now();
-    println!(
-        "Part 1: {} in {:?}",
-        part_1(INPUT, (200000000000000.0, 400000000000000.0)),
-        Instant::now().duration_since(s)
-    );
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input, (200000000000000.0, 400000000000000.0)).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
 }
 
 #[derive(Debug)]
 struct Position {
     x: f64,
     y: f64,
+    #[allow(dead_code)]
     z: f64,
 }
 
@@ -28,6 +42,7 @@ impl Position {
 struct Velocity {
     x: f64,
     y: f64,
+    #[allow(dead_code)]
     z: f64,
 }
 
@@ -44,21 +59,15 @@ struct Hailstone {
 }
 
 impl Hailstone {
-    fn from_line(line: &str) -> Self {
-        let (positions, velocities) = line.split_once(" @ ").unwrap();
-        let positions: Vec<f64> = positions
-            .split(", ")
-            .map(|s| s.parse::<f64>().unwrap())
-            .collect();
-        let velocities: Vec<f64> = velocities
-            .split(", ")
-            .map(|s| s.parse::<f64>().unwrap())
-            .collect();
-
-        Self {
-            position: Position::new(positions[0], positions[1], positions[2]),
-            velocity: Velocity::new(velocities[0], velocities[1], velocities[2]),
-        }
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (positions, velocities) = split_once(line, " @ ")?;
+        let [px, py, pz] = coordinates(positions)?;
+        let [vx, vy, vz] = coordinates(velocities)?;
+
+        Ok(Self {
+            position: Position::new(px, py, pz),
+            velocity: Velocity::new(vx, vy, vz),
+        })
     }
 
     fn overlap_position(&self, other: &Hailstone) -> Option<Position> {
@@ -68,11 +77,9 @@ impl Hailstone {
         }
         let time: f64 = ((other.position.x - self.position.x) * other.velocity.y
             - (other.position.y - self.position.y) * other.velocity.x)
-            as f64
             / det;
         let time2: f64 = ((self.position.x - other.position.x) * self.velocity.y
             - (self.position.y - other.position.y) * self.velocity.x)
-            as f64
             / det;
         if time < 0.0 {
             return None;
@@ -80,20 +87,33 @@ impl Hailstone {
         if time * time2 > 0.0 {
             return None;
         }
-        let x = self.position.x as f64 + self.velocity.x as f64 * time;
-        let y = self.position.y as f64 + self.velocity.y as f64 * time;
+        let x = self.position.x + self.velocity.x * time;
+        let y = self.position.y + self.velocity.y * time;
 
         Some(Position { x, y, z: 0.0 })
     }
 }
 
-fn part_1(input: &str, range: (f64, f64)) -> usize {
-    let hailstones = input
-        .lines()
-        .map(Hailstone::from_line)
-        .collect::<Vec<Hailstone>>();
+// Parses `x, y, z`.
+fn coordinates(text: &str) -> Result<[f64; 3], ParseError> {
+    let values = text
+        .split(',')
+        .map(|s| parse(s.trim(), "a number"))
+        .collect::<Result<Vec<f64>, _>>()?;
+    values
+        .try_into()
+        .map_err(|_| ParseError::new(text, "expected three coordinates"))
+}
+
+pub fn part_1(input: &str, range: (f64, f64)) -> Result<usize, ParseError> {
+    let hailstones = parse_phase(|| {
+        input
+            .lines()
+            .map(Hailstone::from_line)
+            .collect::<Result<Vec<Hailstone>, _>>()
+    })?;
 
-    get_overlapped_within_range(&hailstones, range)
+    Ok(get_overlapped_within_range(&hailstones, range))
 }
 
 fn get_overlapped_within_range(hailstones: &[Hailstone], allowed_range: (f64, f64)) -> usize {
@@ -116,13 +136,9 @@ fn get_overlapped_within_range(hailstones: &[Hailstone], allowed_range: (f64, f6
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::example_input;
 
-    const TEST: &str = "\
-19, 13, 30 @ -2, 1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @ 1, -5, -3";
+    aoc_common::generated_tests!(Day24, [2, 20, 100]);
 
     // round the values from overlap function to 3 decimal places to match example tests rounding
     fn round_to_precision(x: f64, precision: u32) -> f64 {
@@ -132,8 +148,8 @@ mod tests {
 
     #[test]
     fn test_overlap_1() {
-        let hailstone_1 = Hailstone::from_line("19, 13, 30 @ -2, 1, -2");
-        let hailstone_2 = Hailstone::from_line("18, 19, 22 @ -1, -1, -2");
+        let hailstone_1 = Hailstone::from_line("19, 13, 30 @ -2, 1, -2").unwrap();
+        let hailstone_2 = Hailstone::from_line("18, 19, 22 @ -1, -1, -2").unwrap();
 
         let res = hailstone_1.overlap_position(&hailstone_2).unwrap();
 
@@ -143,8 +159,8 @@ mod tests {
 
     #[test]
     fn test_overlap_2() {
-        let hailstone_1 = Hailstone::from_line("19, 13, 30 @ -2, 1, -2");
-        let hailstone_2 = Hailstone::from_line("20, 25, 34 @ -2, -2, -4");
+        let hailstone_1 = Hailstone::from_line("19, 13, 30 @ -2, 1, -2").unwrap();
+        let hailstone_2 = Hailstone::from_line("20, 25, 34 @ -2, -2, -4").unwrap();
 
         let res = hailstone_1.overlap_position(&hailstone_2).unwrap();
 
@@ -154,6 +170,6 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(part_1(TEST, (7.0, 27.0)), 2);
+        assert_eq!(part_1(&example_input!("example.txt"), (7.0, 27.0)), Ok(2));
     }
 }
@@ -0,0 +1,136 @@
+//! Random wiring diagrams that three wires split in two.
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated diagram. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The components in each of the two groups, at least 5.
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            left: 750,
+            right: 750,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(components: usize) -> Self {
+        Params {
+            left: (components / 2).max(5),
+            right: (components - components / 2).max(5),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Components with three-letter names, one per line with the components it is wired to
+/// as `abc: def ghi`, listing each wire once. Cutting three wires splits them into groups of
+/// `left` and `right`, and no other three wires split them.
+///
+/// Panics if either group has fewer than 5 components or there are more than 26³.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(
+        params.left >= 5 && params.right >= 5,
+        "groups need 5 components"
+    );
+    let count = params.left + params.right;
+    assert!(count <= 26 * 26 * 26, "too many components to name");
+
+    let mut names = HashSet::new();
+    while names.len() < count {
+        let name: String = (0..3)
+            .map(|_| char::from(b'a' + rng.range(0..26_u8)))
+            .collect();
+        names.insert(name);
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort_unstable();
+    rng.shuffle(&mut names);
+    let (left, right) = names.split_at(params.left);
+
+    let mut wires = group(rng, left);
+    wires.extend(group(rng, right));
+    let total = wires.len() + 3;
+    while wires.len() < total {
+        let wire = (rng.choose(left), rng.choose(right));
+        if !wires.contains(&wire) {
+            wires.push(wire);
+        }
+    }
+
+    // Each wire is listed under one of its ends
+    let mut lists: HashMap<&String, Vec<&String>> = HashMap::new();
+    for (a, b) in wires {
+        let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        lists.entry(from).or_default().push(to);
+    }
+    let mut lines: Vec<String> = names
+        .iter()
+        .filter_map(|name| {
+            let mut others = lists.remove(name)?;
+            rng.shuffle(&mut others);
+            let others: Vec<&str> = others.iter().map(|other| other.as_str()).collect();
+            Some(format!("{name}: {}", others.join(" ")))
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    let mut input = lines.join("\n");
+    input.push('\n');
+    input
+}
+
+// The wires of a group of components: two loops through all of them with no wire in common.
+// Splitting the group cuts each loop at least twice, so takes at least four wires.
+fn group<'a>(rng: &mut Rng, names: &'a [String]) -> Vec<(&'a String, &'a String)> {
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    let edges = |order: &[usize]| -> Vec<(usize, usize)> {
+        (0..order.len())
+            .map(|i| {
+                let (a, b) = (order[i], order[(i + 1) % order.len()]);
+                (a.min(b), a.max(b))
+            })
+            .collect()
+    };
+    rng.shuffle(&mut order);
+    let first = edges(&order);
+    let taken: HashSet<_> = first.iter().copied().collect();
+    let second = loop {
+        rng.shuffle(&mut order);
+        let second = edges(&order);
+        if second.iter().all(|edge| !taken.contains(edge)) {
+            break second;
+        }
+    };
+    first
+        .into_iter()
+        .chain(second)
+        .map(|(a, b)| (&names[a], &names[b]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part_1, part_1_lib};
+
+    #[test]
+    fn test_cut_splits_the_groups() {
+        for (seed, left, right) in [(0, 5, 5), (1, 6, 20), (2, 50, 30)] {
+            let input = generate(&mut Rng::new(seed), &Params { left, right });
+            assert_eq!(part_1_lib(&input), Ok(left * right));
+            assert_eq!(part_1(&input), Ok(left * right));
+        }
+    }
+}
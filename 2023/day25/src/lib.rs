@@ -0,0 +1,159 @@
+use aoc_common::generate::Rng;
+use aoc_common::parse::adjacency;
+use aoc_common::search::{bfs, bfs_distances};
+use aoc_common::union_find::KeyedUnionFind;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+use rustworkx_core::petgraph::graphmap::UnGraphMap;
+use std::collections::{HashMap, HashSet};
+
+pub mod generate;
+
+// Every component connected to every other it has a wire to, both ways round
+#[derive(Debug, Default)]
+struct Graph<'a> {
+    nodes: HashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> Graph<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut graph = Graph::default();
+        for l in input.lines() {
+            let (node, others) = adjacency(l, ":", " ")?;
+            for other in others {
+                graph.nodes.entry(node).or_default().push(other);
+                graph.nodes.entry(other).or_default().push(node);
+            }
+        }
+        Ok(graph)
+    }
+
+    // The wires of a smallest cut between `source` and `sink`, if it is under `limit` wires.
+    // Each wire carries one unit of flow either way, and the flow is pushed along shortest
+    // paths until no more fits, at which point the cut is the wires leaving everything the
+    // source can still reach.
+    fn cut(&self, source: &'a str, sink: &'a str, limit: usize) -> Option<Vec<(&'a str, &'a str)>> {
+        // The net flow along each wire, kept for both directions with opposite signs
+        let mut flow: HashMap<(&str, &str), i32> = HashMap::new();
+        let residual = |flow: &HashMap<(&str, &str), i32>, node: &&'a str| {
+            self.nodes[node]
+                .iter()
+                .copied()
+                .filter(|&next| flow.get(&(*node, next)).copied().unwrap_or(0) < 1)
+                .collect::<Vec<_>>()
+        };
+
+        for _ in 0..limit {
+            let Some(found) = bfs(source, |node| residual(&flow, node), |&node| node == sink)
+            else {
+                let reachable = bfs_distances(source, |node| residual(&flow, node));
+                let cut = reachable
+                    .keys()
+                    .flat_map(|&node| self.nodes[node].iter().map(move |&next| (node, next)))
+                    .filter(|(_, next)| !reachable.contains_key(next))
+                    .collect();
+                return Some(cut);
+            };
+            for pair in found.path.windows(2) {
+                *flow.entry((pair[0], pair[1])).or_default() += 1;
+                *flow.entry((pair[1], pair[0])).or_default() -= 1;
+            }
+        }
+        None
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1_lib(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1_lib(input: &str) -> Result<usize, ParseError> {
+    let edges = parse_phase(|| {
+        let mut edges: HashSet<(&str, &str)> = HashSet::new();
+        for l in input.lines() {
+            let (n, others) = adjacency(l, ":", " ")?;
+            edges.extend(others.into_iter().map(|e| (n, e)));
+        }
+        Ok(edges)
+    })?;
+    let graph = UnGraphMap::<&str, ()>::from_edges(edges);
+    let Ok(Some((_, cut))) = stoer_wagner_min_cut(&graph, |_| Ok::<_, ()>(1)) else {
+        return Err(too_few_components(input));
+    };
+    Ok(cut.len() * (graph.node_count() - cut.len()))
+}
+
+// Finds the three wires to cut by hand, without a graph library
+#[allow(dead_code)]
+fn part_1(input: &str) -> Result<usize, ParseError> {
+    let graph = parse_phase(|| Graph::parse(input))?;
+    let mut nodes = graph.nodes.keys().copied();
+    let source = nodes.next().ok_or_else(|| too_few_components(input))?;
+
+    // Some component is on the other side of the cut, and three wires are all that join it
+    // to the source
+    let cut = nodes
+        .find_map(|sink| graph.cut(source, sink, 4))
+        .ok_or_else(|| ParseError::end(input, "no three wires split the components in two"))?;
+
+    let mut groups = KeyedUnionFind::new();
+    for (&node, others) in &graph.nodes {
+        groups.insert(node);
+        for &other in others {
+            if !cut.contains(&(node, other)) && !cut.contains(&(other, node)) {
+                groups.union(node, other);
+            }
+        }
+    }
+    let sizes = groups.component_sizes();
+    if sizes.len() != 2 {
+        return Err(ParseError::end(
+            input,
+            "the components were already apart before cutting any wires",
+        ));
+    }
+    Ok(sizes[0] * sizes[1])
+}
+
+fn too_few_components(input: &str) -> ParseError {
+    ParseError::end(input, "expected wires joining at least two components")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day25);
+    aoc_common::generated_tests!(Day25, [10, 40, 200]);
+
+    #[test]
+    fn test_part_1_by_hand() {
+        let input = aoc_common::example_input!("example.txt");
+        assert_eq!(part_1(&input), Ok(54));
+    }
+
+    #[test]
+    fn test_no_wires() {
+        assert!(part_1_lib("").is_err());
+        assert!(part_1("").is_err());
+        // Too well connected for three wires to split
+        let input = "a: b c d e\nb: c d e\nc: d e\nd: e\n";
+        assert!(part_1(input).is_err());
+    }
+}
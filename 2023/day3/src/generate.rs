@@ -0,0 +1,80 @@
+//! Random engine schematics.
+
+use aoc_common::generate::{Generator, Rng};
+
+const SYMBOLS: [char; 9] = ['#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// The shape of a generated schematic. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// The width and height of the schematic.
+    pub side: usize,
+    /// How likely each free cell is to start a part number.
+    pub numbers: f64,
+    /// How likely each free cell is to be a symbol.
+    pub symbols: f64,
+    /// How likely each symbol is to be a `*`, which might be a gear.
+    pub gears: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            side: 140,
+            numbers: 0.07,
+            symbols: 0.04,
+            gears: 0.5,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(side: usize) -> Self {
+        Params {
+            side,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// A square schematic of numbers from 1 to 999 and symbols among `.`s. Numbers on a row are
+/// always kept apart.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut input = String::new();
+    for _ in 0..params.side {
+        let mut row = String::new();
+        while row.len() < params.side {
+            let room = params.side - row.len();
+            if rng.chance(params.numbers) {
+                let number = rng.range(1..1000_u32).to_string();
+                if number.len() <= room {
+                    row.push_str(&number);
+                    // something other than a digit has to end the number
+                    if row.len() < params.side {
+                        row.push(symbol(rng, params).unwrap_or('.'));
+                    }
+                    continue;
+                }
+            }
+            row.push(symbol(rng, params).unwrap_or('.'));
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+    input
+}
+
+fn symbol(rng: &mut Rng, params: &Params) -> Option<char> {
+    if !rng.chance(params.symbols) {
+        return None;
+    }
+    Some(if rng.chance(params.gears) {
+        '*'
+    } else {
+        *rng.choose(&SYMBOLS)
+    })
+}
@@ -0,0 +1,126 @@
+use aoc_common::generate::Rng;
+use aoc_common::grid::{Grid, Position};
+use aoc_common::parse::parse;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(solve_part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(solve_part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Number {
+    integer: u32,
+    row: usize,
+    // The columns the digits span, end exclusive
+    start: usize,
+    end: usize,
+}
+
+impl Number {
+    fn is_next_to(&self, grid: &Grid<char>, position: Position) -> bool {
+        grid.all_neighbors(position)
+            .any(|(row, col)| row == self.row && (self.start..self.end).contains(&col))
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c.is_ascii_punctuation() && c != '.'
+}
+
+// The schematic, which must be plain ASCII, and the numbers in it
+fn parse_schematic(input: &str) -> Result<(Grid<char>, Vec<Number>), ParseError> {
+    parse_phase(|| {
+        let grid = Grid::parse(input, |c| c.is_ascii_graphic().then_some(c))?;
+
+        let mut numbers = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            let mut col = 0;
+            while col < line.len() {
+                let digits = line[col..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(line.len() - col);
+                if digits == 0 {
+                    col += 1;
+                    continue;
+                }
+                numbers.push(Number {
+                    integer: parse(&line[col..col + digits], "a part number")?,
+                    row,
+                    start: col,
+                    end: col + digits,
+                });
+                col += digits;
+            }
+        }
+        Ok((grid, numbers))
+    })
+}
+
+pub fn solve_part_1(input: &str) -> Result<u32, ParseError> {
+    let (grid, numbers) = parse_schematic(input)?;
+
+    Ok(numbers
+        .iter()
+        .filter(|n| {
+            (n.start..n.end)
+                .flat_map(|col| grid.all_neighbors((n.row, col)))
+                .any(|position| is_symbol(grid[position]))
+        })
+        .map(|n| n.integer)
+        .sum())
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let (grid, numbers) = parse_schematic(input)?;
+
+    let mut gear_ratios: Vec<u64> = Vec::new();
+
+    for (position, &c) in grid.iter() {
+        if c != '*' {
+            continue;
+        }
+
+        let adjacents: Vec<u64> = numbers
+            .iter()
+            .filter(|n| n.is_next_to(&grid, position))
+            .map(|n| u64::from(n.integer))
+            .collect();
+
+        // a gear is a `*` next to exactly two part numbers
+        if adjacents.len() == 2 {
+            gear_ratios.push(adjacents[0] * adjacents[1])
+        }
+    }
+
+    Ok(gear_ratios.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day3);
+    aoc_common::generated_tests!(Day3, [1, 10, 40]);
+}
@@ -0,0 +1,96 @@
+//! Random piles of scratchcards.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated pile. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub cards: usize,
+    pub winning: usize,
+    pub ours: usize,
+    /// The largest number on a card, at most 99 to line up in the columns.
+    pub max_number: u32,
+    /// The most copies of any one card that winning can make. Copies grow exponentially with
+    /// the number of wins, and part 2 counts them in a `u32`.
+    pub max_copies: u32,
+    /// Never win copies of cards past the end of the pile, which the puzzle promises.
+    pub stay_in_pile: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            cards: 215,
+            winning: 10,
+            ours: 25,
+            max_number: 99,
+            max_copies: 100_000,
+            stay_in_pile: true,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(cards: usize) -> Self {
+        Params {
+            cards,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Cards like `Card   1: 41 48 83 | 83 86  6 31 48`, where neither list has repeats.
+///
+/// Panics if there aren't `winning + ours` numbers up to `max_number`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(
+        (params.winning + params.ours) as u32 <= params.max_number,
+        "not enough numbers to fill a card"
+    );
+    let mut numbers: Vec<u32> = (1..=params.max_number).collect();
+    // copies[i] is how many copies of card i the cards before it have won so far
+    let mut copies = vec![1_u32; params.cards];
+    let mut input = String::new();
+    for card in 0..params.cards {
+        let mut wins = rng.range(0..=params.winning.min(params.ours));
+        if params.stay_in_pile {
+            wins = wins.min(params.cards - card - 1);
+        }
+        // stop short of any card the wins would give too many copies
+        let held = copies[card];
+        let won = |later: usize| {
+            copies
+                .get(card + later)
+                .is_none_or(|&n| n.saturating_add(held) <= params.max_copies)
+        };
+        wins = (1..=wins).take_while(|&later| won(later)).count();
+        for n in copies.iter_mut().skip(card + 1).take(wins) {
+            *n += held;
+        }
+
+        rng.shuffle(&mut numbers);
+        let winning = &numbers[..params.winning];
+        let others = &numbers[params.winning..params.winning + params.ours - wins];
+        let mut ours: Vec<u32> = winning[..wins].iter().chain(others).copied().collect();
+        rng.shuffle(&mut ours);
+
+        let column = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:3}: {} | {}\n",
+            card + 1,
+            column(winning),
+            column(&ours)
+        ));
+    }
+    input
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::{numbers, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::trace;
+
+pub mod generate;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+#[derive(Debug)]
+struct Card {
+    count: u32,
+    wins: u32,
+}
+
+// The winning numbers and the numbers we have on a card
+type Numbers = (Vec<u32>, Vec<u32>);
+
+fn deserialize(input: &str) -> Result<Vec<Numbers>, ParseError> {
+    parse_phase(|| {
+        input
+            .lines()
+            .map(|line| {
+                let (_, line) = split_once(line, ":")?;
+                let (winners, ours) = split_once(line, "|")?;
+                Ok((numbers(winners, "a number")?, numbers(ours, "a number")?))
+            })
+            .collect()
+    })
+}
+
+pub fn part_1(input: &str) -> Result<u32, ParseError> {
+    let mut sum = 0;
+    let cards = deserialize(input)?;
+    for (winners, numbers) in cards {
+        let mut line_worth: u32 = 0;
+
+        for number in numbers {
+            if winners.contains(&number) {
+                if line_worth == 0 {
+                    line_worth += 1;
+                } else {
+                    line_worth += line_worth;
+                }
+            }
+        }
+        sum += line_worth;
+    }
+
+    Ok(sum)
+}
+
+pub fn part_2(input: &str) -> Result<u32, ParseError> {
+    let mut total = 0;
+    let mut card_map: HashMap<usize, Card> = HashMap::new();
+
+    let cards = deserialize(input)?;
+    let mut idx = 0;
+
+    while idx < cards.len() {
+        let mut wins: u32 = 0;
+
+        let card = card_map.entry(idx).or_insert(Card { count: 0, wins: 0 });
+        card.count += 1;
+
+        let (winners, numbers) = &cards[idx];
+
+        for number in numbers {
+            if winners.contains(number) {
+                wins += 1;
+            }
+        }
+        card.wins = wins;
+        let final_card_count = card.count;
+        let wins: usize = wins.try_into().expect("will fit into usize");
+        if wins != 0 {
+            for i in idx + 1..=idx + wins {
+                let future_card = card_map.entry(i).or_insert(Card { count: 0, wins: 0 });
+                future_card.count += final_card_count;
+            }
+        }
+        idx += 1;
+    }
+    trace!(?card_map, "won every copy");
+    for card in card_map.values() {
+        total += card.count;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day4);
+    aoc_common::generated_tests!(Day4, [1, 20, 215]);
+}
@@ -0,0 +1,101 @@
+//! Random almanacs.
+
+use std::collections::BTreeSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// The shape of a generated almanac. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub seed_pairs: usize,
+    /// The most ranges in one map.
+    pub max_ranges: usize,
+    /// Every number is below this.
+    pub limit: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            seed_pairs: 10,
+            max_ranges: 50,
+            limit: 1 << 32,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(max_ranges: usize) -> Self {
+        Params {
+            max_ranges,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// An almanac whose maps lead from seeds to locations. The seed ranges don't overlap, and
+/// each map moves touching ranges of sources onto touching, shuffled ranges of destinations,
+/// so no two of its ranges overlap either.
+///
+/// Panics if `limit` is too small to split into that many ranges.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let ends = cuts(rng, params.seed_pairs * 2, params.limit);
+    let mut pairs: Vec<(i64, i64)> = ends
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1] - pair[0]))
+        .collect();
+    rng.shuffle(&mut pairs);
+    let seeds: Vec<String> = pairs
+        .iter()
+        .map(|(start, len)| format!("{start} {len}"))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        input.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+        let ranges = rng.range(1..=params.max_ranges.max(1));
+        let ends = cuts(rng, ranges + 1, params.limit);
+        let mut sources: Vec<(i64, i64)> = ends.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let covered = ends[ends.len() - 1] - ends[0];
+        rng.shuffle(&mut sources);
+        let mut destination = rng.range(0..=params.limit - covered);
+        let mut ranges: Vec<String> = sources
+            .iter()
+            .map(|&(source, len)| {
+                destination += len;
+                format!("{} {source} {len}", destination - len)
+            })
+            .collect();
+        rng.shuffle(&mut ranges);
+        for range in ranges {
+            input.push_str(&range);
+            input.push('\n');
+        }
+    }
+    input
+}
+
+// `n` different numbers below `limit`, in order
+fn cuts(rng: &mut Rng, n: usize, limit: i64) -> Vec<i64> {
+    assert!(n as i64 <= limit, "can't pick {n} numbers below {limit}");
+    let mut cuts = BTreeSet::new();
+    while cuts.len() < n {
+        cuts.insert(rng.range(0..limit));
+    }
+    cuts.into_iter().collect()
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::generate::Rng;
+use aoc_common::interval::{Interval, IntervalSet};
+use aoc_common::parse::{numbers, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::{debug, trace};
+
+pub mod generate;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+#[derive(Debug)]
+struct Category {
+    destination: String,
+    ranges: Vec<Range>,
+}
+
+// Moves the values in `source` by `offset`
+#[derive(Debug)]
+struct Range {
+    source: Interval,
+    offset: i64,
+}
+
+impl Category {
+    // Where each of `values` ends up in the destination category. A value is moved by the
+    // first range that holds it, and kept as it is if none do.
+    fn convert(&self, values: &IntervalSet) -> IntervalSet {
+        let mut unmapped = values.clone();
+        let mut mapped = IntervalSet::new();
+        for range in &self.ranges {
+            let source = IntervalSet::from(range.source);
+            mapped = mapped.union(&unmapped.intersection(&source).shift(range.offset));
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+// The lowest location any of the `seeds` ends up at, `None` if there are none. Parsing checked
+// the maps don't loop.
+fn lowest_location(seeds: IntervalSet, map: &HashMap<&str, Category>) -> Option<i64> {
+    let mut source = "seed";
+    let mut values = seeds;
+    while let Some(category) = map.get(source) {
+        values = category.convert(&values);
+        source = &category.destination;
+    }
+    values.min()
+}
+
+// The seed numbers, in pairs for part 2, and the maps keyed by the category they convert from
+fn deserialize(
+    input: &str,
+    pairs: bool,
+) -> Result<(Vec<i64>, HashMap<&str, Category>), ParseError> {
+    parse_phase(|| {
+        let mut lines = input.lines().filter(|l| !l.is_empty());
+        let first = lines
+            .next()
+            .ok_or_else(|| ParseError::end(input, "expected the seeds"))?;
+        let seeds: Vec<i64> = first
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::new(first, "expected \"seeds:\""))
+            .and_then(|seeds| numbers(seeds, "a seed number"))?;
+        if seeds.is_empty() {
+            return Err(ParseError::new(first, "expected seed numbers"));
+        }
+        if pairs && !seeds.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                first,
+                "expected pairs of seed numbers, a start and a length",
+            ));
+        }
+
+        let mut map: HashMap<&str, Category> = HashMap::new();
+        let mut current_source = None;
+        for line in lines {
+            if let Some(name) = line.strip_suffix(" map:") {
+                let (source, destination) = split_once(name, "-to-")?;
+                current_source = Some(source);
+                let category = Category {
+                    destination: destination.to_string(),
+                    ranges: Vec::new(),
+                };
+                if map.insert(source, category).is_some() {
+                    return Err(ParseError::new(line, format!("duplicate map for {source}")));
+                }
+            } else {
+                let category = current_source
+                    .and_then(|source| map.get_mut(source))
+                    .ok_or_else(|| ParseError::new(line, "expected a map header"))?;
+                let numbers: Vec<i64> = numbers(line, "a number")?;
+                let [destination_range_start, source_range_start, range_length] = numbers[..]
+                else {
+                    return Err(ParseError::new(line, "expected three numbers"));
+                };
+                category.ranges.push(Range {
+                    source: Interval::with_len(source_range_start, range_length),
+                    offset: destination_range_start - source_range_start,
+                })
+            }
+        }
+
+        // Following the maps from seeds has to come to an end
+        let mut seen = HashSet::new();
+        let mut source = "seed";
+        while let Some((&name, category)) = map.get_key_value(source) {
+            if !seen.insert(name) {
+                return Err(ParseError::new(name, "the maps loop back to this category"));
+            }
+            source = &category.destination;
+        }
+
+        debug!(
+            seeds = seeds.len(),
+            categories = map.len(),
+            "read the almanac"
+        );
+        trace!(?map);
+
+        Ok((seeds, map))
+    })
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let (seeds, map) = deserialize(input, true)?;
+    let seed_ranges = seeds
+        .chunks_exact(2)
+        .map(|pair| Interval::with_len(pair[0], pair[1]))
+        .collect();
+    lowest_location(seed_ranges, &map)
+        .ok_or_else(|| ParseError::end(input, "every range of seeds is empty"))
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let (seeds, map) = deserialize(input, false)?;
+    let seeds = seeds
+        .iter()
+        .map(|&seed| Interval::with_len(seed, 1))
+        .collect();
+    Ok(lowest_location(seeds, &map).expect("parsing checked there are seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day5);
+    aoc_common::generated_tests!(Day5, [1, 5, 50]);
+
+    #[test]
+    fn test_seeds() {
+        let map = "\nseed-to-soil map:\n50 98 2\n";
+        assert_eq!(part_1(&format!("seeds: 98{map}")), Ok(50));
+        assert!(part_2(&format!("seeds: 98{map}")).is_err());
+        assert_eq!(part_2(&format!("seeds: 5 0 97 2{map}")), Ok(50));
+        assert!(part_2(&format!("seeds: 5 0{map}")).is_err());
+    }
+
+    #[test]
+    fn test_maps_loop() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n\nsoil-to-seed map:\n";
+        let error = part_1(input).unwrap_err();
+        assert_eq!(error.message, "the maps loop back to this category");
+    }
+    #[test]
+    fn test_duplicate_map() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n2 1 1\n\nseed-to-soil map:\n5 1 1\n";
+        let error = part_1(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "duplicate map for seed");
+        assert_eq!(error.position, Some((6, 1)));
+    }
+}
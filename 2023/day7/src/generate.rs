@@ -0,0 +1,75 @@
+//! Random lists of Camel Cards hands.
+
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// How many of each card make up each kind of hand, from five of a kind to high card
+const KINDS: [&[usize]; 7] = [
+    &[5],
+    &[4, 1],
+    &[3, 2],
+    &[3, 1, 1],
+    &[2, 2, 1],
+    &[2, 1, 1, 1],
+    &[1, 1, 1, 1, 1],
+];
+
+/// The shape of a generated list. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub hands: usize,
+    pub max_bid: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            hands: 1000,
+            max_bid: 1000,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(hands: usize) -> Self {
+        Params {
+            hands,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Different hands with their bids, like `32T3K 765`. Every kind of hand is as likely as
+/// any other, counting jokers as the cards they are.
+///
+/// Panics if there are more hands than different hands of five cards.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.hands <= 13_usize.pow(5), "not that many hands");
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < params.hands {
+        let mut cards = CARDS;
+        rng.shuffle(&mut cards);
+        let mut hand: Vec<char> = rng
+            .choose(&KINDS)
+            .iter()
+            .zip(cards)
+            .flat_map(|(&count, card)| [card].repeat(count))
+            .collect();
+        rng.shuffle(&mut hand);
+        let hand: String = hand.into_iter().collect();
+        if seen.insert(hand.clone()) {
+            input.push_str(&format!("{hand} {}\n", rng.range(1..=params.max_bid)));
+        }
+    }
+    input
+}
@@ -1,14 +1,11 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-const INPUT: &str = include_str!("./input.txt");
-const TEST: &str = "\
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483\
-";
+use aoc_common::generate::Rng;
+use aoc_common::parse::{parse, split_once};
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+use tracing::trace;
+
+pub mod generate;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
 enum Card {
@@ -28,22 +25,22 @@ enum Card {
 }
 
 impl Card {
-    fn from(c: char) -> Self {
+    fn from(c: char) -> Option<Self> {
         match c {
-            'A' => Card::A,
-            'K' => Card::K,
-            'Q' => Card::Q,
-            'J' => Card::J,
-            'T' => Card::T,
-            '9' => Card::Nine,
-            '8' => Card::Eight,
-            '7' => Card::Seven,
-            '6' => Card::Six,
-            '5' => Card::Five,
-            '4' => Card::Four,
-            '3' => Card::Three,
-            '2' => Card::Two,
-            _ => unreachable!(), // only these cards are present
+            'A' => Some(Card::A),
+            'K' => Some(Card::K),
+            'Q' => Some(Card::Q),
+            'J' => Some(Card::J),
+            'T' => Some(Card::T),
+            '9' => Some(Card::Nine),
+            '8' => Some(Card::Eight),
+            '7' => Some(Card::Seven),
+            '6' => Some(Card::Six),
+            '5' => Some(Card::Five),
+            '4' => Some(Card::Four),
+            '3' => Some(Card::Three),
+            '2' => Some(Card::Two),
+            _ => None,
         }
     }
 }
@@ -66,7 +63,7 @@ impl HandKind {
             let count = map.entry(card).or_insert(0);
             *count += 1;
         }
-        let joker_count = map.get(&Card::J).unwrap_or(&0).clone();
+        let joker_count = *map.get(&Card::J).unwrap_or(&0);
         let mut counts: Vec<u8> = map.into_values().collect();
         counts.sort();
         match (counts.as_slice(), joker_count) {
@@ -135,57 +132,58 @@ impl Ord for Hand {
     }
 }
 
-fn main() {
-    let solve = part_1(INPUT);
-    println!("{solve}");
-}
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn year(&self) -> u16 {
+        2023
+    }
 
-fn part_1(input: &str) -> u64 {
-    let start = Instant::now();
-    let mut hands: Vec<Hand> = Vec::new();
-    for line in input.lines() {
-        let mut split = line.split_ascii_whitespace();
-        let all_cards = split.next().unwrap();
-        let cards: [Card; 5] = all_cards
-            .chars()
-            .map(Card::from)
-            .collect::<Vec<_>>()
-            .as_slice()
-            .try_into()
-            .unwrap();
-
-        let bid = split.next().unwrap().parse().unwrap();
-        let hand = Hand::new(cards, bid);
-        hands.push(hand);
+    fn day(&self) -> u8 {
+        7
     }
 
-    let end = Instant::now();
-    println!("Parsing time: {:?}", end.duration_since(start));
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
 
-    // println!("{:?}", hands);
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
 
-    let start = Instant::now();
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    let mut hands = parse_phase(|| {
+        let mut hands: Vec<Hand> = Vec::new();
+        for line in input.lines() {
+            let (all_cards, bid) = split_once(line, " ")?;
+            let cards: [Card; 5] = all_cards
+                .chars()
+                .map(Card::from)
+                .collect::<Option<Vec<_>>>()
+                .and_then(|cards| cards.try_into().ok())
+                .ok_or_else(|| ParseError::new(all_cards, "expected five cards"))?;
+
+            let bid = parse(bid.trim(), "a bid")?;
+            let hand = Hand::new(cards, bid);
+            hands.push(hand);
+        }
+        Ok(hands)
+    })?;
 
     hands.sort();
-    let solve = hands
+    trace!(?hands, "ranked the hands");
+    Ok(hands
         .iter()
         .enumerate()
         .map(|(idx, &hand)| hand.bid * (idx as u64 + 1))
-        .fold(0, |a, c| a + c);
-
-    let end = Instant::now();
-
-    println!("Processing time: {:?}", end.duration_since(start));
-
-    solve
+        .sum())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_2() {
-        assert_eq!(part_1(TEST), 5905);
-    }
+    aoc_common::example_tests!(Day7);
+    aoc_common::generated_tests!(Day7, [1, 20, 1000]);
 }
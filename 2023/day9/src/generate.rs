@@ -0,0 +1,68 @@
+//! Random OASIS reports.
+
+use aoc_common::generate::{Generator, Rng};
+
+/// The shape of a generated report. The defaults match the real inputs.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub sequences: usize,
+    pub len: usize,
+    /// The highest degree of polynomial a sequence follows. The puzzle needs at least one
+    /// value more than fixing the polynomial takes, so anything over `len - 2` is lowered.
+    pub max_degree: usize,
+    /// The largest first value at each level of differences.
+    pub max_start: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            sequences: 200,
+            len: 21,
+            max_degree: 18,
+            max_start: 20,
+        }
+    }
+}
+
+impl Generator for Params {
+    fn sized(sequences: usize) -> Self {
+        Params {
+            sequences,
+            ..Params::default()
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        generate(rng, self)
+    }
+}
+
+/// Sequences whose differences settle down to a constant, built from the bottom up: the
+/// constant, then each level of differences summed from a random first value.
+///
+/// Panics if `len` is less than 2.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.len >= 2, "a sequence needs two values to continue");
+    let max = params.max_start;
+    let mut input = String::new();
+    for _ in 0..params.sequences {
+        let degree = rng.range(0..=params.max_degree.min(params.len - 2));
+        let mut values = vec![rng.range(-max..=max); params.len];
+        for _ in 0..degree {
+            let mut value = rng.range(-max..=max);
+            values = values
+                .iter()
+                .map(|difference| {
+                    let previous = value;
+                    value += difference;
+                    previous
+                })
+                .collect();
+        }
+        let values: Vec<String> = values.iter().map(i64::to_string).collect();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    input
+}
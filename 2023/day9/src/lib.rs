@@ -0,0 +1,72 @@
+use aoc_common::generate::Rng;
+use aoc_common::parse::numbers;
+use aoc_common::polynomial::Newton;
+use aoc_common::{parse_phase, Answer, ParseError, PartResult, Solution};
+
+pub mod generate;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_1(&self, input: &str) -> PartResult {
+        Some(part_1(input).map(Answer::from))
+    }
+
+    fn part_2(&self, input: &str) -> PartResult {
+        Some(part_2(input).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }
+}
+
+pub fn part_1(input: &str) -> Result<i128, ParseError> {
+    let sequences = deserialize(input)?;
+
+    // each sequence's values were at 0, 1, 2, ... so the next is at its length
+    Ok(sequences
+        .iter()
+        .map(|(len, polynomial)| polynomial.at(*len as i128))
+        .sum())
+}
+
+pub fn part_2(input: &str) -> Result<i128, ParseError> {
+    let sequences = deserialize(input)?;
+
+    Ok(sequences
+        .iter()
+        .map(|(_, polynomial)| polynomial.at(-1))
+        .sum())
+}
+
+// Each sequence's length, and the polynomial its values follow
+fn deserialize(input: &str) -> Result<Vec<(usize, Newton)>, ParseError> {
+    parse_phase(|| {
+        input
+            .lines()
+            .map(|l| {
+                let values: Vec<i128> = numbers(l, "a number")?;
+                let polynomial =
+                    Newton::fit(&values).map_err(|e| ParseError::new(l, e.to_string()))?;
+                Ok((values.len(), polynomial))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests!(Day9);
+    aoc_common::generated_tests!(Day9, [1, 20, 200]);
+}
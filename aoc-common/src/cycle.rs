@@ -0,0 +1,172 @@
+//! Finding where a repeatedly stepped state starts repeating, to skip ahead through
+//! simulations far too long to run.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How the states reached by stepping from a start repeat: after the first `prefix` states,
+/// the same `period` states come round again and again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`, where step 0 is the start.
+    pub fn earliest(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Brent's algorithm, which only ever keeps two states, so suits states too big to store
+/// many of. It never returns if the states don't repeat.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period by moving the tortoise up to the hare at every power of two, until the
+    // hare laps it
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, they first meet where the cycle starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Finds the cycle by remembering every state until one comes round again, stepping each only
+/// once. Also returns every state up to the repeat, so step `n`'s state is
+/// `states[cycle.earliest(n)]`. It never returns if the states don't repeat.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    match walk(start, step, None) {
+        Ok(found) => found,
+        Err(_) => unreachable!("the walk only stops early with a limit"),
+    }
+}
+
+/// The state after `n` steps from `start`, stepping at most until the states repeat.
+pub fn nth_state<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    match walk(start, step, Some(n)) {
+        Ok((cycle, mut states)) => states.swap_remove(cycle.earliest(n)),
+        Err(state) => state,
+    }
+}
+
+// Steps from `start` until a state repeats, or until step `limit` is reached first, in which
+// case that step's state is the error
+fn walk<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: Option<usize>,
+) -> Result<(Cycle, Vec<S>), S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        let n = states.len();
+        if limit == Some(n) {
+            return Err(state);
+        }
+        match seen.entry(state) {
+            Entry::Occupied(entry) => {
+                let prefix = *entry.get();
+                let cycle = Cycle {
+                    prefix,
+                    period: n - prefix,
+                };
+                return Ok((cycle, states));
+            }
+            Entry::Vacant(entry) => {
+                state = step(entry.key());
+                states.push(entry.key().clone());
+                entry.insert(n);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // 2, 4, 16, 256 % 255 = 1, 1, ... reaches 1 after three steps then stays
+    fn square(n: &u64) -> u64 {
+        n * n % 255
+    }
+
+    #[test]
+    fn test_cycle() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 1,
+        };
+        assert_eq!(brent(2, square), expected);
+        let (cycle, states) = find_cycle(2, square);
+        assert_eq!((cycle, states), (expected, vec![2, 4, 16, 1]));
+
+        // 0 → 1 → 2 → 3 → 4 → 2
+        let step = |&n: &u32| if n == 4 { 2 } else { n + 1 };
+        let expected = Cycle {
+            prefix: 2,
+            period: 3,
+        };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(find_cycle(0, step).0, expected);
+        assert_eq!(expected.earliest(1), 1);
+        assert_eq!(expected.earliest(5), 2);
+        assert_eq!(nth_state(0, step, 1_000_000_000), 4);
+        assert_eq!(nth_state(0, step, 3), 3);
+        assert_eq!(nth_state(0, step, 0), 0);
+    }
+
+    proptest! {
+        // Any function from a finite set to itself cycles, so step through a random table
+        #[test]
+        fn prop_cycle(
+            (next, start) in (1..40_usize).prop_flat_map(|len| {
+                (prop::collection::vec(0..len, len), 0..len)
+            }),
+            n in 0..100_usize,
+        ) {
+            let step = |&i: &usize| next[i];
+            let expected: Vec<_> = std::iter::successors(Some(start), |i| Some(step(i)))
+                .take(n + 1)
+                .collect();
+            prop_assert_eq!(nth_state(start, step, n), expected[n]);
+
+            let cycle = brent(start, step);
+            prop_assert_eq!(find_cycle(start, step).0, cycle);
+            // The states repeat after the prefix, and not sooner or with a shorter period
+            let state = |n| nth_state(start, step, n);
+            prop_assert_eq!(state(cycle.prefix), state(cycle.prefix + cycle.period));
+            prop_assert!((1..cycle.period).all(|p| state(cycle.prefix) != state(cycle.prefix + p)));
+            if cycle.prefix > 0 {
+                prop_assert_ne!(state(cycle.prefix - 1), state(cycle.prefix - 1 + cycle.period));
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Differential testing: checking a fast solution against a slow one that is obviously correct
+//! on many small generated inputs, and shrinking any input they disagree on.
+
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate::Rng;
+
+/// Checks that `fast` gives the same answer as `reference` on `cases` of the inputs `generate`
+/// makes from seeds 0, 1, 2, ...
+///
+/// An input the reference panics on doesn't count as one the puzzle could give, so it is
+/// skipped, and the reference can check the assumptions the fast solution makes. On the first
+/// input they disagree on, or where `fast` panics, panics with the seed and the input shrunk to
+/// as few lines as still show the difference. Also panics if the reference rejects so many
+/// inputs that `cases` of them take over ten times as many seeds.
+pub fn assert_agree<T: PartialEq + Debug>(
+    cases: u64,
+    mut generate: impl FnMut(&mut Rng) -> String,
+    fast: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) {
+    // The two answers for an input the reference solves, `None` from `fast` if it panicked
+    let answers = |input: &str| {
+        let expected = panic::catch_unwind(AssertUnwindSafe(|| reference(input))).ok()?;
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| fast(input))).ok();
+        Some((answer, expected))
+    };
+    let disagree = |input: &str| {
+        answers(input).is_some_and(|(answer, expected)| answer.as_ref() != Some(&expected))
+    };
+
+    let mut checked = 0;
+    for seed in 0.. {
+        if checked == cases {
+            break;
+        }
+        assert!(
+            seed < 10 * cases,
+            "the reference panicked on {} of the first {seed} inputs",
+            seed - checked
+        );
+        let input = generate(&mut Rng::new(seed));
+        let Some((answer, expected)) = answers(&input) else {
+            continue;
+        };
+        checked += 1;
+        if answer.as_ref() == Some(&expected) {
+            continue;
+        }
+
+        let shrunk = shrink(&input, disagree);
+        let (answer, expected) = answers(&shrunk).expect("the shrunk input still disagrees");
+        let answer = match answer {
+            Some(answer) => format!("gave {answer:?}"),
+            None => "panicked".to_string(),
+        };
+        panic!(
+            "seed {seed}: the solution {answer} where the reference gave {expected:?} on this \
+             input, shrunk from {} lines to {}:\n{shrunk}",
+            input.lines().count(),
+            shrunk.lines().count(),
+        );
+    }
+}
+
+/// The shortest input found by removing lines from `input` that `fails` still holds for,
+/// removing runs of lines half as long each time no run can go.
+pub fn shrink(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let join = |lines: &[&str]| {
+        let mut joined = lines.join("\n");
+        if input.ends_with('\n') && !joined.is_empty() {
+            joined.push('\n');
+        }
+        joined
+    };
+
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut run = lines.len().div_ceil(2);
+    while run > 0 {
+        let mut start = 0;
+        let mut removed = false;
+        while start < lines.len() {
+            let end = (start + run).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            if fails(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed {
+            run /= 2;
+        }
+    }
+    join(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink() {
+        // Fails while both lines with an `x` are left
+        let input = "a\nx1\nb\nc\nd\nx2\ne\n";
+        let fails = |input: &str| input.contains("x1\n") && input.contains("x2\n");
+        assert_eq!(shrink(input, fails), "x1\nx2\n");
+        assert_eq!(shrink("a\nb", |input| input.contains('b')), "b");
+    }
+
+    #[test]
+    fn test_agree() {
+        let count = |input: &str| input.lines().count();
+        assert_agree(10, |rng| "x\n".repeat(rng.range(0..10)), count, count);
+    }
+
+    #[test]
+    fn test_reference_panics_are_skipped() {
+        // The reference only takes even numbers of lines, and the solution gets odd ones wrong
+        let count = |input: &str| input.lines().count() / 2 * 2;
+        let even = |input: &str| {
+            assert!(input.lines().count().is_multiple_of(2));
+            input.lines().count()
+        };
+        assert_agree(10, |rng| "x\n".repeat(rng.range(0..10)), count, even);
+
+        let message = panic::catch_unwind(|| {
+            assert_agree(3, |_| "x\n".to_string(), count, even);
+        })
+        .unwrap_err();
+        assert_eq!(
+            message.downcast_ref::<String>().unwrap(),
+            "the reference panicked on 30 of the first 30 inputs"
+        );
+    }
+
+    #[test]
+    fn test_disagreement_is_shrunk() {
+        let message = panic::catch_unwind(|| {
+            assert_agree(
+                1,
+                |_| "a\nbbbb\nc\ndddd\n".to_string(),
+                |input: &str| input.contains("bbbb"),
+                |_: &str| false,
+            )
+        })
+        .unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(
+            message.ends_with("shrunk from 4 lines to 1:\nbbbb\n"),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Part, Solution};
+
+/// A puzzle example kept in a day's `examples/` directory.
+///
+/// The file starts with one `part N: answer` line per known answer, then a blank line,
+/// then the input exactly as the solution should see it. A file without those header
+/// lines is all input, for examples only used by hand-written tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+impl Example {
+    pub fn parse(name: &str, text: &str) -> Result<Self, String> {
+        let mut expected = Vec::new();
+        let mut rest = text;
+        while let Some(line) = rest.lines().next().and_then(|l| l.strip_prefix("part ")) {
+            let (part, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("{name}: expected `part N: answer`, got {line:?}"))?;
+            let part = part
+                .trim()
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| format!("{name}: part must be 1 or 2, got {part:?}"))?;
+            expected.push((part, answer.trim().to_string()));
+            rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+        }
+
+        if !expected.is_empty() {
+            rest = rest
+                .strip_prefix("\r\n")
+                .or_else(|| rest.strip_prefix('\n'))
+                .ok_or_else(|| format!("{name}: expected a blank line after the answers"))?;
+        }
+
+        Ok(Example {
+            name: name.to_string(),
+            input: rest.to_string(),
+            expected,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Self::parse(&name, &text)
+    }
+}
+
+/// Every `*.txt` example in `dir`, sorted by file name.
+pub fn load_dir(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("could not read {}: {e}", dir.display()))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| Example::load(path)).collect()
+}
+
+/// Runs `solution` on every example in `dir` and panics listing every wrong answer. Each
+/// example is run with both `\n` and `\r\n` line endings, whichever its file was saved with,
+/// and both must give the same answers.
+pub fn assert_examples(solution: &dyn Solution, dir: &str) {
+    let examples = load_dir(Path::new(dir)).unwrap_or_else(|e| panic!("{e}"));
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for example in &examples {
+        let lf = example.input.replace("\r\n", "\n");
+        let crlf = lf.replace('\n', "\r\n");
+        for (part, expected) in &example.expected {
+            checked += 1;
+            for (input, endings) in [(&lf, ""), (&crlf, " with \\r\\n endings")] {
+                let name = format!("{} part {part}{endings}", example.name);
+                match solution.solve(*part, input) {
+                    Some(Ok(answer)) if answer.as_str() == expected => {}
+                    Some(Ok(answer)) => {
+                        failures.push(format!("{name}: expected {expected}, got {answer}"))
+                    }
+                    Some(Err(e)) => failures.push(format!("{name}: {}", e.report(input))),
+                    None => failures.push(format!(
+                        "{name}: expected {expected}, but the part is unsolved"
+                    )),
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "no example answers found in {dir}");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Generates a test that checks a day's [`Solution`] against every file in its `examples/`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:expr) => {
+        #[test]
+        fn test_examples() {
+            $crate::examples::assert_examples(
+                &$solution,
+                concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            );
+        }
+    };
+}
+
+/// The input of one file in the calling crate's `examples/`, for tests that need
+/// something other than the default parameters.
+#[macro_export]
+macro_rules! example_input {
+    ($name:literal) => {
+        $crate::examples::Example::load(::std::path::Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/examples/",
+            $name
+        )))
+        .unwrap_or_else(|e| panic!("{e}"))
+        .input
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_answers() {
+        let example = Example::parse("a.txt", "part 1: 102\npart 2: 94\n\n24\n13\n\n5\n").unwrap();
+        assert_eq!(
+            example.expected,
+            [
+                (Part::One, "102".to_string()),
+                (Part::Two, "94".to_string())
+            ]
+        );
+        assert_eq!(example.input, "24\n13\n\n5\n");
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let example =
+            Example::parse("a.txt", "part 1: 102\r\npart 2: 94\r\n\r\n24\r\n13\r\n").unwrap();
+        assert_eq!(
+            example.expected,
+            [
+                (Part::One, "102".to_string()),
+                (Part::Two, "94".to_string())
+            ]
+        );
+        assert_eq!(example.input, "24\r\n13\r\n");
+    }
+
+    // Answers part 1 with the input's length once `\r\n` is read as one character
+    struct Length;
+
+    impl Solution for Length {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> crate::PartResult {
+            Some(Ok(input.replace("\r\n", "\n").len().into()))
+        }
+    }
+
+    #[test]
+    fn test_assert_crlf_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("crlf.txt"), "part 1: 6\r\n\r\n24\r\n13\r\n").unwrap();
+        assert_examples(&Length, dir.to_str().unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_input_only() {
+        let example = Example::parse("b.txt", "#.#\n...").unwrap();
+        assert!(example.expected.is_empty());
+        assert_eq!(example.input, "#.#\n...");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Example::parse("c.txt", "part 3: 1\n\nx").unwrap_err(),
+            "c.txt: part must be 1 or 2, got \"3\""
+        );
+        assert_eq!(
+            Example::parse("d.txt", "part 1: 1\nx").unwrap_err(),
+            "d.txt: expected a blank line after the answers"
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! Random puzzle inputs, for testing solutions on more than the examples without sharing real
+//! inputs. Each day that can make its own inputs does so through [`Solution::generate`], from
+//! an [`Rng`] seeded so that any input can be made again.
+
+use std::collections::HashMap;
+use std::ops::{Bound, RangeBounds};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, Position};
+use crate::{Part, Solution};
+
+/// A small, fast random number generator (SplitMix64). It is written out here rather than
+/// taken from a crate so that a seed makes the same input for as long as the generators do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number picked evenly from `range`, which must have both ends and not be empty.
+    pub fn range<T: Uniform>(&mut self, range: impl RangeBounds<T>) -> T {
+        let low = match range.start_bound() {
+            Bound::Included(&low) => low.to_i128(),
+            Bound::Excluded(&low) => low.to_i128() + 1,
+            Bound::Unbounded => panic!("a range to pick from needs a start"),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(&high) => high.to_i128(),
+            Bound::Excluded(&high) => high.to_i128() - 1,
+            Bound::Unbounded => panic!("a range to pick from needs an end"),
+        };
+        assert!(low <= high, "picked from an empty range");
+        T::from_i128(low + self.below((high - low) as u128 + 1) as i128)
+    }
+
+    // A number below `n`, at most 2^64. Values from the top that don't make up a whole run of
+    // `n` are thrown away, as taking their remainder would favour small numbers.
+    fn below(&mut self, n: u128) -> u64 {
+        let Ok(n) = u64::try_from(n) else {
+            return self.next_u64();
+        };
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return x % n;
+            }
+        }
+    }
+
+    /// An index into something of length `len`, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len)
+    }
+
+    /// A number in `0.0..1.0`.
+    pub fn unit(&mut self) -> f64 {
+        // the top 53 bits fill an f64's mantissa
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.unit() < p
+    }
+
+    /// Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// The integers [`Rng::range`] can pick.
+pub trait Uniform: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+uniform!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A random shape of about `cells` cells grown from the middle of a `width` by `height` grid,
+/// which is all in one piece, has no holes, and never touches itself only at a corner. Its
+/// edge is then a simple loop, as [`outline`] walks it.
+///
+/// The shape can stop short of `cells` if it runs out of places to grow.
+pub fn region(rng: &mut Rng, width: usize, height: usize, cells: usize) -> Grid<bool> {
+    let mut region = Grid::filled(width, height, false);
+    let start = (height / 2, width / 2);
+    region[start] = true;
+    let mut inside = vec![start];
+    let mut misses = 0;
+    while inside.len() < cells && misses < 100 * cells {
+        let from = inside[rng.index(inside.len())];
+        match region.step(from, *rng.choose(&Direction::ALL)) {
+            Some(next) if !region[next] && keeps_disk(&region, next) => {
+                region[next] = true;
+                inside.push(next);
+                misses = 0;
+            }
+            _ => misses += 1,
+        }
+    }
+    region
+}
+
+// Whether adding the cell at `position` keeps the region a disk: the cell must meet it along
+// one unbroken stretch of its own edge, with at least one side shared. Meeting it at two
+// separate places would close a hole or pinch the outline.
+fn keeps_disk(region: &Grid<bool>, position: Position) -> bool {
+    // Around the cell's edge clockwise from the top left corner: corner, side, corner, ...
+    const RING: [(isize, isize); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+    ];
+    let filled = |offset| {
+        region
+            .step(position, offset)
+            .is_some_and(|next| region[next])
+    };
+    let mut touches = [false; 8];
+    for (i, &offset) in RING.iter().enumerate() {
+        // a corner is shared if either side next to it is
+        touches[i] =
+            filled(offset) || i % 2 == 0 && (filled(RING[(i + 7) % 8]) || filled(RING[i + 1]));
+    }
+    let sides = (1..8).step_by(2).any(|i| touches[i]);
+    let stretches = (0..8)
+        .filter(|&i| touches[i] && !touches[(i + 7) % 8])
+        .count();
+    sides && stretches == 1
+}
+
+/// The corners of a [`region`]'s outline, going clockwise from its top left corner. Corner
+/// `Point::new(x, y)` is the top left of cell `(y, x)`.
+///
+/// Panics if the region is empty or isn't a shape `region` could grow.
+pub fn outline(region: &Grid<bool>) -> Vec<Point> {
+    // Each side of the region's edge, going clockwise so the region is on its right
+    let mut next = HashMap::new();
+    for ((row, column), _) in region.iter().filter(|(_, &inside)| inside) {
+        let corner = |dy: usize, dx: usize| Point::new((column + dx) as i64, (row + dy) as i64);
+        let outside = |direction| {
+            region
+                .step((row, column), direction)
+                .is_none_or(|p| !region[p])
+        };
+        for (direction, from, to) in [
+            (Direction::Up, corner(0, 0), corner(0, 1)),
+            (Direction::Right, corner(0, 1), corner(1, 1)),
+            (Direction::Down, corner(1, 1), corner(1, 0)),
+            (Direction::Left, corner(1, 0), corner(0, 0)),
+        ] {
+            if outside(direction) {
+                let previous = next.insert(from, to);
+                assert!(previous.is_none(), "the region touches itself at {from:?}");
+            }
+        }
+    }
+
+    let start = *next.keys().min().expect("the region is empty");
+    let mut path = vec![start];
+    let mut point = next[&start];
+    while point != start {
+        path.push(point);
+        point = next[&point];
+    }
+    assert_eq!(path.len(), next.len(), "the region's edge isn't one loop");
+
+    // keep only the points where the outline turns
+    let len = path.len();
+    (0..len)
+        .filter(|&i| path[i] - path[(i + len - 1) % len] != path[(i + 1) % len] - path[i])
+        .map(|i| path[i])
+        .collect()
+}
+
+/// The settings a day's generator makes inputs from, whose [`Default`] is shaped like the
+/// real inputs.
+pub trait Generator: Default {
+    /// The settings for an input of about `size`, in the sense [`Solution::generate`] uses.
+    fn sized(size: usize) -> Self;
+
+    /// A random input made with these settings.
+    fn generate(&self, rng: &mut Rng) -> String;
+}
+
+/// An input made with `G`'s settings for `size`, or its defaults without one, for days to
+/// forward [`Solution::generate`] to.
+pub fn generate_sized<G: Generator>(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let params = size.map_or_else(G::default, G::sized);
+    Some(params.generate(rng))
+}
+
+/// Checks that `solution` makes the same input twice from a seed, and that every part solves
+/// the inputs it makes of each size without panicking or failing to parse them. Panics
+/// listing the seed and size of every input that failed, to pass to `aoc generate`.
+pub fn assert_generated(solution: &dyn Solution, sizes: &[usize]) {
+    const SEEDS: u64 = 8;
+
+    let mut failures = Vec::new();
+    for &size in sizes {
+        for seed in 0..SEEDS {
+            let name = format!("seed {seed} size {size}");
+            let input = solution
+                .generate(&mut Rng::new(seed), Some(size))
+                .unwrap_or_else(|| panic!("day {} has no generator", solution.day()));
+            if solution.generate(&mut Rng::new(seed), Some(size)).as_ref() != Some(&input) {
+                failures.push(format!("{name}: made a different input the second time"));
+            }
+            for part in Part::BOTH {
+                let solved = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, &input)));
+                match solved {
+                    Ok(Some(Err(e))) => {
+                        failures.push(format!("{name} part {part}: {}", e.report(&input)))
+                    }
+                    Ok(_) => {}
+                    Err(_) => failures.push(format!("{name} part {part}: panicked")),
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Generates a test that runs a day's [`Solution`] on inputs it generates of each size.
+#[macro_export]
+macro_rules! generated_tests {
+    ($solution:expr, $sizes:expr) => {
+        #[test]
+        fn test_generated() {
+            $crate::generate::assert_generated(&$solution, &$sizes);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Polygon;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    #[test]
+    fn test_rng_is_stable() {
+        // Changing these changes every generated input
+        let mut rng = Rng::new(0);
+        assert_eq!(
+            [rng.next_u64(), rng.next_u64(), rng.next_u64()],
+            [
+                0xe220_a839_7b1d_cdaf,
+                0x6e78_9e6a_a1b9_65f4,
+                0x06c4_5d18_8009_454f
+            ]
+        );
+        assert_eq!(Rng::new(7), Rng::new(7));
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            seen[rng.range(0..5_usize)] = true;
+            let x: i64 = rng.range(-3..=3);
+            assert!((-3..=3).contains(&x));
+        }
+        assert_eq!(seen, [true; 5]);
+        assert_eq!(rng.range(7..=7_u8), 7);
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut items: Vec<u32> = (0..20).collect();
+        Rng::new(2).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_outline() {
+        // An L, whose outline has six corners
+        let mut region = Grid::filled(3, 2, false);
+        for position in [(0, 0), (1, 0), (1, 1), (1, 2)] {
+            region[position] = true;
+        }
+        assert_eq!(
+            outline(&region),
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(3, 1),
+                Point::new(3, 2),
+                Point::new(0, 2),
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_region(seed: u64, width in 1..30_usize, height in 1..30_usize, cells in 1..400_usize) {
+            let region = region(&mut Rng::new(seed), width, height, cells);
+            let filled = region.iter().filter(|(_, &inside)| inside).count();
+            prop_assert!(filled <= cells.max(1));
+
+            // A simple outline around exactly the region's cells leaves no holes
+            let outline = Polygon::new(outline(&region));
+            prop_assert!(outline.is_simple());
+            prop_assert!(outline.twice_signed_area() > 0);
+            prop_assert_eq!(outline.area() as usize, filled);
+        }
+    }
+}
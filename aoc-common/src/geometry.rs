@@ -0,0 +1,198 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::grid::Position;
+
+/// One of the four ways to move on a grid. `Up` is towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U`/`D`/`L`/`R`, compass points `N`/`E`/`S`/`W`, or an arrow `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::Up),
+            'R' | 'E' | '>' => Some(Direction::Right),
+            'D' | 'S' | 'v' => Some(Direction::Down),
+            'L' | 'W' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// One step this way as a `(row, column)` offset, for [`Grid::step`](crate::grid::Grid::step).
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    /// One step this way as a [`Point`].
+    pub fn unit(self) -> Point {
+        let (y, x) = self.offset();
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// A point on the unbounded plane, with `y` growing downwards like a grid's rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The point `distance` steps away in `direction`.
+    pub fn step(self, direction: Direction, distance: i64) -> Self {
+        self + direction.unit() * distance
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The grid position at this point, if it isn't left of or above the grid.
+    pub fn to_position(self) -> Option<Position> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, column): Position) -> Self {
+        Point::new(column as i64, row as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.unit()
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().unit(), direction.unit() * -1);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('E'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_point() {
+        let point = Point::ORIGIN.step(Direction::Right, 3) + Direction::Up;
+        assert_eq!(point, Point::new(3, -1));
+        assert_eq!(point.manhattan(Point::new(-1, 1)), 6);
+        assert_eq!(point.to_position(), None);
+        assert_eq!(Point::from((1, 3)).to_position(), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_grid_step() {
+        let grid = Grid::filled(2, 2, '.');
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((1, 1), Direction::Down), None);
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Direction;
+use crate::ParseError;
+
+/// A `(row, column)` position in a [`Grid`].
+pub type Position = (usize, usize);
+
+/// Steps to all eight neighbors, clockwise from up.
+pub const ALL_NEIGHBORS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells stored row by row in one buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid `width` cells wide made of `cells`, row by row.
+    ///
+    /// Panics if `cells` doesn't fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't make rows {width} wide",
+            cells.len()
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a rectangular character map, converting each character with `f`, which
+    /// returns `None` for a character that doesn't belong in the map.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_with_position(input, |_, c| f(c))
+    }
+
+    /// Like [`Grid::parse`], but `f` also receives the position of the character.
+    pub fn parse_with_position(
+        input: &str,
+        mut f: impl FnMut(Position, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(input.len());
+        for (row, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    line,
+                    format!("expected a row {width} wide like the first"),
+                ));
+            }
+            for (column, (i, c)) in line.char_indices().enumerate() {
+                let cell = f((row, column), c).ok_or_else(|| {
+                    ParseError::new(&line[i..i + c.len_utf8()], "unexpected character")
+                })?;
+                cells.push(cell);
+            }
+        }
+        Ok(Self::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The position `offset` away from `position`, if it is still in the grid. The offset is
+    /// either a [`Direction`](crate::geometry::Direction) or a `(row, column)` pair.
+    pub fn step(
+        &self,
+        (row, column): Position,
+        offset: impl Into<(isize, isize)>,
+    ) -> Option<Position> {
+        let offset = offset.into();
+        let next = (
+            row.checked_add_signed(offset.0)?,
+            column.checked_add_signed(offset.1)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four orthogonal neighbors of `position`, in [`Direction::ALL`] order.
+    pub fn neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight neighbors of `position`, diagonals included, in [`ALL_NEIGHBORS`] order.
+    pub fn all_neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_NEIGHBORS
+            .iter()
+            .filter_map(move |&offset| self.step(position, offset))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, and a grid without columns has no rows either
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is outside the grid");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// A grid of the same shape with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.width, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid flipped over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|column| (0..self.height).rev().map(move |row| (row, column)))
+                .map(|position| self[position].clone())
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|column| self.column(column))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+/// Writes the grid back out as text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid, Grid::from_cells(2, vec![1, 2, 3, 4]));
+        assert_eq!(grid[(1, 0)], 3);
+
+        let input = "12\n3x";
+        let error = Grid::parse(input, |c| c.to_digit(10))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character, got \"x\""
+        );
+
+        let input = "12\n345";
+        let error = Grid::parse(input, |c| c.to_digit(10))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a row 2 wide like the first, got \"345\""
+        );
+    }
+
+    #[test]
+    fn test_parse_with_position() {
+        let grid = Grid::parse_with_position("ab\ncd", |position, c| Some((position, c))).unwrap();
+        assert_eq!(grid[(1, 0)], ((1, 0), 'c'));
+        assert_eq!(grid[(0, 1)], ((0, 1), 'b'));
+        assert_eq!(grid.find(|&(_, c)| c == 'd'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 2, '.');
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.step((1, 2), (0, 1)), None);
+        assert_eq!(
+            grid.neighbors((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 1), (0, 0)]
+        );
+        assert_eq!(
+            grid.all_neighbors((1, 0)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse("abc\ndef", Some).unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse("abc\ndef", Some).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Half-open integer intervals, and sets of them, for puzzles that move whole ranges of values
+//! around at once.
+
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`. It is empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// The `len` integers from `start`.
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// How many integers the interval holds.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.start.abs_diff(self.end)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The values in both intervals, which may be empty.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The values below `at`, and the values from `at` on. Either may be empty.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+
+    /// Every value moved by `offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as the fewest intervals that cover it, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    // Sorted, non-empty, and neither overlapping nor touching
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    // Restores the invariant on any list of intervals
+    fn normalized(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_unstable_by_key(|interval| interval.start);
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    /// The intervals making up the set, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// The largest value in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::normalized([&self.intervals[..], &other.intervals[..]].concat())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let both = a.intersection(b);
+            if !both.is_empty() {
+                intervals.push(both);
+            }
+            // Whichever ends first can't overlap anything further on in the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values in this set that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for a in &self.intervals {
+            let mut start = a.start;
+            // Skip the intervals that end before this one starts, which end before later ones too
+            while other.intervals.get(j).is_some_and(|b| b.end <= a.start) {
+                j += 1;
+            }
+            for b in other.intervals[j..].iter().take_while(|b| b.start < a.end) {
+                if start < b.start {
+                    intervals.push(Interval::new(start, b.start));
+                }
+                start = start.max(b.end);
+            }
+            if start < a.end {
+                intervals.push(Interval::new(start, a.end));
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values below `at`, and the values from `at` on.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let (below, above): (Vec<_>, Vec<_>) = self
+            .intervals
+            .iter()
+            .map(|interval| interval.split_at(at))
+            .unzip();
+        (
+            IntervalSet::normalized(below),
+            IntervalSet::normalized(above),
+        )
+    }
+
+    /// Every value moved by `offset`.
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(offset))
+                .collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet::normalized(vec![interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        IntervalSet::normalized(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    // Values the generated sets are drawn from, with room around them for shifts and splits
+    const LOW: i64 = -50;
+    const HIGH: i64 = 50;
+
+    fn interval() -> impl Strategy<Value = Interval> {
+        (LOW..HIGH, -2..20_i64).prop_map(|(start, len)| Interval::with_len(start, len))
+    }
+
+    fn interval_set() -> impl Strategy<Value = IntervalSet> {
+        prop::collection::vec(interval(), 0..6).prop_map(IntervalSet::from_iter)
+    }
+
+    // The set as the plain set of values it stands for
+    fn values(set: &IntervalSet) -> BTreeSet<i64> {
+        set.intervals()
+            .iter()
+            .flat_map(|interval| interval.start..interval.end)
+            .collect()
+    }
+
+    fn assert_normalized(set: &IntervalSet) {
+        for interval in set.intervals() {
+            assert!(!interval.is_empty(), "{set:?} holds an empty interval");
+        }
+        for pair in set.intervals().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?} isn't merged");
+        }
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(3, 7);
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(3) && !interval.contains(7));
+        assert_eq!(Interval::new(7, 3).len(), 0);
+        assert_eq!(
+            interval.split_at(5),
+            (Interval::new(3, 5), Interval::new(5, 7))
+        );
+        assert_eq!(
+            interval.split_at(10),
+            (Interval::new(3, 7), Interval::new(7, 7))
+        );
+        assert_eq!(
+            interval.intersection(&Interval::new(6, 9)),
+            Interval::new(6, 7)
+        );
+        assert!(interval.intersection(&Interval::new(7, 9)).is_empty());
+        assert_eq!(interval.shift(-3).to_string(), "0..4");
+    }
+
+    #[test]
+    fn test_set() {
+        let set: IntervalSet = [
+            Interval::new(5, 8),
+            Interval::new(0, 2),
+            Interval::new(2, 3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.intervals(), [Interval::new(0, 3), Interval::new(5, 8)]);
+        assert_eq!((set.len(), set.min(), set.max()), (6, Some(0), Some(7)));
+
+        let other = IntervalSet::from(Interval::new(1, 6));
+        assert_eq!(
+            set.difference(&other).intervals(),
+            [Interval::new(0, 1), Interval::new(6, 8)]
+        );
+        assert_eq!(
+            set.intersection(&other).intervals(),
+            [Interval::new(1, 3), Interval::new(5, 6)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_operations(a in interval_set(), b in interval_set()) {
+            let (va, vb) = (values(&a), values(&b));
+            for (set, expected) in [
+                (a.union(&b), va.union(&vb).copied().collect::<BTreeSet<_>>()),
+                (a.intersection(&b), va.intersection(&vb).copied().collect()),
+                (a.difference(&b), va.difference(&vb).copied().collect()),
+            ] {
+                assert_normalized(&set);
+                prop_assert_eq!(values(&set), expected);
+            }
+        }
+
+        #[test]
+        fn prop_membership(set in interval_set()) {
+            let expected = values(&set);
+            prop_assert_eq!(set.len(), expected.len() as u64);
+            prop_assert_eq!(set.min(), expected.first().copied());
+            prop_assert_eq!(set.max(), expected.last().copied());
+            for value in LOW - 5..HIGH + 25 {
+                prop_assert_eq!(set.contains(value), expected.contains(&value));
+            }
+        }
+
+        #[test]
+        fn prop_split_at(set in interval_set(), at in LOW - 5..HIGH + 25) {
+            let (below, above) = set.split_at(at);
+            assert_normalized(&below);
+            assert_normalized(&above);
+            prop_assert!(values(&below).iter().all(|&value| value < at));
+            prop_assert!(values(&above).iter().all(|&value| value >= at));
+            prop_assert_eq!(below.union(&above), set);
+        }
+
+        #[test]
+        fn prop_shift(set in interval_set(), offset in -30..30_i64) {
+            let shifted = set.shift(offset);
+            assert_normalized(&shifted);
+            let expected: BTreeSet<_> = values(&set).iter().map(|value| value + offset).collect();
+            prop_assert_eq!(values(&shifted), expected);
+        }
+
+        #[test]
+        fn prop_insert(set in interval_set(), interval in interval()) {
+            let mut inserted = set.clone();
+            inserted.insert(interval);
+            assert_normalized(&inserted);
+            prop_assert_eq!(inserted, set.union(&IntervalSet::from(interval)));
+        }
+    }
+}
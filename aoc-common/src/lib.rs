@@ -0,0 +1,22 @@
+//! Code shared by every day's solution.
+
+pub mod cycle;
+pub mod differential;
+pub mod examples;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod polygon;
+pub mod polynomial;
+pub mod search;
+pub mod solution;
+pub mod timing;
+pub mod union_find;
+pub mod visualize;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Part, PartResult, Solution};
+pub use timing::{measure, parse_phase, Timing};
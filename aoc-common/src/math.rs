@@ -0,0 +1,192 @@
+//! Number theory for puzzles about cycles lining up: gcd and lcm, modular inverses, and the
+//! Chinese Remainder Theorem.
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a.into(), b.into()) as u64
+}
+
+/// [`gcd`] wide enough for any `i128`'s magnitude.
+pub fn gcd_u128(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd_u128(b, a % b)
+    }
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`. The lcm with 0 is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The gcd of every value, which is 0 for none.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The lcm of every value, which is 1 for none, or `None` as soon as it overflows.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` where `g` is the non-negative gcd of `a` and
+/// `b`, and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive, got {modulus}");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// The values `≡ residue (mod modulus)`, such as the steps at which a cycle is at a given
+/// point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    /// Always in `0..modulus`.
+    pub residue: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    /// Panics unless `modulus` is positive.
+    pub fn new(residue: i64, modulus: i64) -> Self {
+        assert!(modulus > 0, "modulus must be positive, got {modulus}");
+        Congruence {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        value.rem_euclid(self.modulus) == self.residue
+    }
+
+    /// The smallest value `>= min` that satisfies the congruence.
+    pub fn first_from(&self, min: i64) -> i64 {
+        min + (self.residue - min).rem_euclid(self.modulus)
+    }
+
+    /// The values satisfying both congruences, which needn't have coprime moduli. `None` if
+    /// no value does, or if the combined modulus doesn't fit in an `i64`.
+    pub fn combine(&self, other: &Congruence) -> Option<Congruence> {
+        let (g, x, _) = extended_gcd(self.modulus, other.modulus);
+        let difference = other.residue - self.residue;
+        if difference % g != 0 {
+            return None;
+        }
+        // x is the inverse of `self.modulus / g` modulo `other.modulus / g`, so stepping from
+        // `self.residue` by `self.modulus` that many times lands on `other.residue`
+        let step = other.modulus / g;
+        let times = (i128::from(difference / g) * i128::from(x)).rem_euclid(i128::from(step));
+        let modulus = i64::try_from(i128::from(self.modulus) * i128::from(step)).ok()?;
+        let residue = i128::from(self.residue) + i128::from(self.modulus) * times;
+        Some(Congruence::new(
+            i64::try_from(residue.rem_euclid(modulus.into())).ok()?,
+            modulus,
+        ))
+    }
+}
+
+/// Solves a system of congruences with the Chinese Remainder Theorem. `None` if they
+/// contradict each other or the combined modulus overflows; no congruences at all are
+/// satisfied by every value.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), |solution, congruence| {
+            solution.combine(&congruence)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd_u128(1 << 100, 3 << 90), 1 << 90);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(gcd_all([12, 18, 8]), 2);
+        assert_eq!(lcm_all([3733, 3793, 3917, 4057]), Some(225_008_752_522_361));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        let solution = crt([
+            Congruence::new(2, 3),
+            Congruence::new(3, 5),
+            Congruence::new(2, 7),
+        ]);
+        assert_eq!(solution, Some(Congruence::new(23, 105)));
+
+        // Moduli sharing a factor agree on it or have no solution at all
+        assert_eq!(
+            crt([Congruence::new(3, 4), Congruence::new(5, 6)]),
+            Some(Congruence::new(11, 12))
+        );
+        assert_eq!(crt([Congruence::new(3, 4), Congruence::new(4, 6)]), None);
+
+        // A cycle first reached on step 5 and every 4 after, and one on 7 and every 6 after
+        let solution = crt([Congruence::new(5, 4), Congruence::new(7, 6)]).unwrap();
+        assert_eq!(solution.first_from(7), 13);
+        assert_eq!(crt([]), Some(Congruence::new(0, 1)));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_extended_gcd(a in -1000..1000_i64, b in -1000..1000_i64) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+        }
+
+        #[test]
+        fn prop_crt(congruences in prop::collection::vec((0..30_i64, 1..12_i64), 0..4)) {
+            let congruences: Vec<_> = congruences
+                .into_iter()
+                .map(|(residue, modulus)| Congruence::new(residue, modulus))
+                .collect();
+            let lcm = lcm_all(congruences.iter().map(|c| c.modulus as u64)).unwrap() as i64;
+            let expected = (0..lcm).find(|&n| congruences.iter().all(|c| c.contains(n)));
+            let solution = crt(congruences.iter().copied());
+            prop_assert_eq!(solution.map(|s| s.residue), expected);
+            if let Some(solution) = solution {
+                prop_assert_eq!(solution.modulus, lcm);
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a puzzle input couldn't be parsed.
+///
+/// Parsers create one from the slice of the input they choke on, without having to keep
+/// the whole input around. [`Solution::solve`](crate::Solution::solve) then finds where
+/// that slice sits in the input, which fills in `position`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    /// 1-based line and column of `text`, when it is known.
+    pub position: Option<(usize, usize)>,
+    // Address of the slice `text` was copied from, used to locate it in the input
+    address: usize,
+}
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of the input for it to be located.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.to_string(),
+            position: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// An error for input that ends before `rest`, the unparsed remainder, was complete.
+    pub fn end(rest: &str, message: impl Into<String>) -> Self {
+        Self::new(&rest[rest.len()..], message)
+    }
+
+    /// Fills in the position, if `text` was sliced from `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.position.is_some() || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+        let before = &input[..self.address - start];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+            + 1;
+        self.position = Some((line, column));
+        self
+    }
+
+    /// The error followed by the offending line of `input` with the text underlined.
+    pub fn report(&self, input: &str) -> String {
+        let Some((line, column)) = self.position else {
+            return self.to_string();
+        };
+        let source = input.lines().nth(line - 1).unwrap_or_default();
+        let number = line.to_string();
+        let width = self.text.lines().next().unwrap_or_default().chars().count();
+        format!(
+            "{self}\n{number} | {source}\n{} | {}{}",
+            " ".repeat(number.len()),
+            " ".repeat(column - 1),
+            "^".repeat(width.max(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((line, column)) = self.position {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        if self.text.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}, got {:?}", self.message, self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text` as a number, or any other [`FromStr`] type, naming what was expected on failure.
+pub fn parse<T: FromStr>(text: &str, what: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, format!("expected {what}")))
+}
+
+/// Splits `text` around the first `separator`, which must be there.
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(text, format!("expected {separator:?}")))
+}
+
+/// Parses every whitespace separated word of `text`, naming what each should be on failure.
+pub fn numbers<T: FromStr>(text: &str, what: &str) -> Result<Vec<T>, ParseError> {
+    text.split_ascii_whitespace()
+        .map(|word| parse(word, what))
+        .collect()
+}
+
+/// Every integer in `text`, skipping whatever is between them. A `-` right before the digits
+/// makes one negative.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    let mut integers = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let start = if rest[..start].ends_with('-') {
+            start - 1
+        } else {
+            start
+        };
+        let end = rest[start + 1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |end| start + 1 + end);
+        integers.push(parse(&rest[start..end], "an integer that fits")?);
+        rest = &rest[end..];
+    }
+    Ok(integers)
+}
+
+/// The blocks of lines between blank lines, without their line endings. `\r\n` line endings,
+/// blank lines at either end and runs of several blank lines are all fine.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.lines().peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
+        }
+        // Both are slices of `input`, so the paragraph is everything from one to the other
+        let start = first.as_ptr() as usize - input.as_ptr() as usize;
+        let end = last.as_ptr() as usize - input.as_ptr() as usize + last.len();
+        Some(&input[start..end])
+    })
+}
+
+/// The `key` and `value` of each item in a record like `x=787,m=2655`, where `separator`
+/// comes between items and `assign` between a key and its value.
+pub fn key_values<'a>(
+    text: &'a str,
+    separator: &str,
+    assign: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    text.split(separator)
+        .map(|item| split_once(item, assign))
+        .collect()
+}
+
+/// A node and the nodes it leads to from a line like `a -> b, c`, where `arrow` comes after
+/// the node and `separator` between the others. A whitespace `separator` matches any run of
+/// whitespace.
+pub fn adjacency<'a>(
+    line: &'a str,
+    arrow: &str,
+    separator: &str,
+) -> Result<(&'a str, Vec<&'a str>), ParseError> {
+    let (node, rest) = split_once(line, arrow)?;
+    let others: Vec<&str> = if separator.trim().is_empty() {
+        rest.split_whitespace().collect()
+    } else {
+        rest.split(separator).map(str::trim).collect()
+    };
+    if let Some(empty) = others.iter().find(|other| other.is_empty()) {
+        return Err(ParseError::new(empty, "expected a node name"));
+    }
+    if others.is_empty() {
+        return Err(ParseError::end(line, "expected a node name"));
+    }
+    Ok((node.trim(), others))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "32T3K 765\nT55J5 6x4\n";
+        let text = &input[16..19];
+        let error = parse::<u32>(text, "a bid").unwrap_err().locate(input);
+        assert_eq!(error.position, Some((2, 7)));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected a bid, got \"6x4\""
+        );
+        assert_eq!(
+            error.report(input),
+            "line 2, column 7: expected a bid, got \"6x4\"\n2 | T55J5 6x4\n  |       ^^^"
+        );
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let copy = String::from("6x4");
+        let error = parse::<u32>(&copy, "a bid")
+            .unwrap_err()
+            .locate("T55J5 6x4");
+        assert_eq!(error.position, None);
+        assert_eq!(error.report("T55J5 6x4"), "expected a bid, got \"6x4\"");
+    }
+
+    #[test]
+    fn test_end() {
+        let input = "seeds: 1 2\n";
+        let error = ParseError::end(input, "expected a map").locate(input);
+        assert_eq!(error.to_string(), "line 2, column 1: expected a map");
+        assert_eq!(
+            split_once("seeds 1 2", ": ").unwrap_err().to_string(),
+            "expected \": \", got \"seeds 1 2\""
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<i64>("0 -3  12", "a value"), Ok(vec![0, -3, 12]));
+        let input = "seeds: 79 1x4";
+        let error = numbers::<u32>(&input[7..], "a seed")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected a seed, got \"1x4\""
+        );
+
+        assert_eq!(
+            integers::<i64>("Game 12: x=-3, y=4-5 @ 7"),
+            Ok(vec![12, -3, 4, -5, 7])
+        );
+        assert_eq!(integers::<u8>("no numbers"), Ok(vec![]));
+        let input = "a=1,b=300";
+        let error = integers::<u8>(input).unwrap_err().locate(input);
+        assert_eq!(error.position, Some((1, 7)));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let expected = ["a\nb", "c"];
+        assert_eq!(paragraphs("a\nb\n\nc\n").collect::<Vec<_>>(), expected);
+        assert_eq!(paragraphs("\na\nb\n\n\n\nc").collect::<Vec<_>>(), expected);
+        assert_eq!(
+            paragraphs("a\r\nb\r\n\r\nc\r\n").collect::<Vec<_>>(),
+            ["a\r\nb", "c"]
+        );
+        assert_eq!(paragraphs("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_records() {
+        assert_eq!(
+            key_values("x=787,m=2655", ",", "="),
+            Ok(vec![("x", "787"), ("m", "2655")])
+        );
+        assert!(key_values("x=787,m", ",", "=").is_err());
+
+        assert_eq!(
+            adjacency("%a -> b, c", " -> ", ","),
+            Ok(("%a", vec!["b", "c"]))
+        );
+        assert_eq!(
+            adjacency("jqt: rhn  xhk", ":", " "),
+            Ok(("jqt", vec!["rhn", "xhk"]))
+        );
+        let input = "a -> b, , c";
+        let error = adjacency(input, " -> ", ",").unwrap_err().locate(input);
+        assert_eq!(error.position, Some((1, 8)));
+        assert!(adjacency("a -> ", " -> ", ",").is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Exact area and lattice-point counts for polygons with integer vertices.
+
+use crate::geometry::Point;
+use crate::math::gcd;
+
+/// A closed polygon, given by its vertices in order around it. The last vertex joins back up
+/// to the first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Repeated vertices, including a last one that closes the loop back on the first, are
+    /// dropped.
+    pub fn new(vertices: impl IntoIterator<Item = Point>) -> Self {
+        let mut vertices: Vec<Point> = vertices.into_iter().collect();
+        vertices.dedup();
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Every edge as its two ends, starting from the first vertex and ending with the edge
+    /// back to it.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the signed area by the shoelace formula, which keeps it whole. It is positive when
+    /// the vertices go clockwise as drawn on a grid, with `y` growing downwards.
+    pub fn twice_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y))
+            .sum()
+    }
+
+    /// The area, which is a whole number or a half.
+    pub fn area(&self) -> f64 {
+        self.twice_signed_area().unsigned_abs() as f64 / 2.0
+    }
+
+    /// The number of integer points on the edges.
+    pub fn boundary_points(&self) -> u128 {
+        match self.vertices[..] {
+            [] => return 0,
+            [_] => return 1,
+            // a segment, which the edges would go along and back
+            [a, b] => return u128::from(gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y))) + 1,
+            _ => {}
+        }
+        self.edges()
+            .map(|(a, b)| u128::from(gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y))))
+            .sum()
+    }
+
+    /// The number of integer points strictly inside, by Pick's theorem. Only meaningful for
+    /// a [simple](Polygon::is_simple) polygon. A polygon of fewer than 3 vertices has none.
+    pub fn interior_points(&self) -> u128 {
+        if self.vertices.len() < 3 {
+            return 0;
+        }
+        // Pick's theorem: area = interior + boundary / 2 - 1
+        let twice_area = self.twice_signed_area().unsigned_abs();
+        (twice_area + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    /// The number of integer points inside or on the edges, like the cells a loop on a grid
+    /// covers.
+    pub fn lattice_points(&self) -> u128 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Two edges that cross, touch or overlap anywhere besides the vertex joining neighboring
+    /// edges, as their indices in [`Polygon::edges`].
+    pub fn self_intersection(&self) -> Option<(usize, usize)> {
+        let edges: Vec<_> = self.edges().collect();
+        let n = edges.len();
+        for i in 0..n {
+            for j in i + 1..n {
+                let (a, b) = edges[i];
+                let (c, d) = edges[j];
+                let intersects = if j == i + 1 {
+                    // b is c; they only meet elsewhere if the second folds back over the first
+                    folds_back(a, b, d)
+                } else if i == 0 && j == n - 1 {
+                    // d is a
+                    folds_back(b, a, c)
+                } else {
+                    segments_intersect((a, b), (c, d))
+                };
+                if intersects {
+                    return Some((i, j));
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether no two edges meet except where neighbors join.
+    pub fn is_simple(&self) -> bool {
+        self.self_intersection().is_none()
+    }
+}
+
+impl FromIterator<Point> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Polygon::new(iter)
+    }
+}
+
+// The z component of (b - a) × (c - a): positive if c is clockwise of b around a, as drawn
+fn cross(a: Point, b: Point, c: Point) -> i128 {
+    let (ab, ac) = (b - a, c - a);
+    i128::from(ab.x) * i128::from(ac.y) - i128::from(ab.y) * i128::from(ac.x)
+}
+
+// Whether p is on the segment from a to b, given that the three are collinear
+fn within(p: Point, (a, b): (Point, Point)) -> bool {
+    a.x.min(b.x) <= p.x && p.x <= a.x.max(b.x) && a.y.min(b.y) <= p.y && p.y <= a.y.max(b.y)
+}
+
+fn segments_intersect((a, b): (Point, Point), (c, d): (Point, Point)) -> bool {
+    let (d1, d2) = (cross(c, d, a), cross(c, d, b));
+    let (d3, d4) = (cross(a, b, c), cross(a, b, d));
+    if d1.signum() * d2.signum() < 0 && d3.signum() * d4.signum() < 0 {
+        return true;
+    }
+    (d1 == 0 && within(a, (c, d)))
+        || (d2 == 0 && within(b, (c, d)))
+        || (d3 == 0 && within(c, (a, b)))
+        || (d4 == 0 && within(d, (a, b)))
+}
+
+// Whether the edge from `joint` to `c` runs back along the edge from `a` to `joint`
+fn folds_back(a: Point, joint: Point, c: Point) -> bool {
+    let (ab, bc) = (joint - a, c - joint);
+    cross(a, joint, c) == 0
+        && i128::from(ab.x) * i128::from(bc.x) + i128::from(ab.y) * i128::from(bc.y) < 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        vertices.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_square() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)]);
+        assert_eq!(square.vertices().len(), 4);
+        assert_eq!(square.twice_signed_area(), 32);
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let reversed = polygon(&[(0, 4), (4, 4), (4, 0), (0, 0)]);
+        assert_eq!(reversed.twice_signed_area(), -32);
+        assert_eq!(reversed.interior_points(), 9);
+    }
+
+    #[test]
+    fn test_triangle() {
+        // The slanted edge from the origin passes through (2, 1) on the way
+        let triangle = polygon(&[(0, 0), (4, 2), (0, 3)]);
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.boundary_points(), 2 + 1 + 3);
+        assert_eq!(triangle.interior_points(), 4);
+
+        let thin = polygon(&[(0, 0), (3, 1), (1, 1)]);
+        assert_eq!(thin.twice_signed_area(), 2);
+        assert_eq!(thin.interior_points(), 0);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let empty = polygon(&[]);
+        assert_eq!(empty.interior_points(), 0);
+        assert_eq!(empty.lattice_points(), 0);
+
+        let point = polygon(&[(1, 1)]);
+        assert_eq!(point.interior_points(), 0);
+        assert_eq!(point.lattice_points(), 1);
+
+        let segment = polygon(&[(0, 0), (4, 2)]);
+        assert_eq!(segment.boundary_points(), 3);
+        assert_eq!(segment.interior_points(), 0);
+        assert_eq!(segment.lattice_points(), 3);
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        assert!(polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]).is_simple());
+
+        let bowtie = polygon(&[(0, 0), (2, 2), (2, 0), (0, 2)]);
+        assert_eq!(bowtie.self_intersection(), Some((0, 2)));
+
+        // The third edge runs back over the second
+        let spike = polygon(&[(0, 0), (4, 0), (4, 4), (4, 2), (0, 2)]);
+        assert_eq!(spike.self_intersection(), Some((1, 2)));
+
+        // The vertex at (2, 0) touches the first edge
+        let touching = polygon(&[(0, 0), (4, 0), (4, 4), (2, 0), (0, 4)]);
+        assert!(!touching.is_simple());
+    }
+}
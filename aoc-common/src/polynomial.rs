@@ -0,0 +1,286 @@
+//! Exact polynomial interpolation, for sequences that continue a pattern of differences.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::math::gcd_u128;
+
+/// Why values couldn't be fitted with a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitError {
+    /// A polynomial of the degree asked for needs more values to pin it down.
+    TooFewValues { needed: usize, got: usize },
+    /// No polynomial of at most this degree goes through every value.
+    NotPolynomial { max_degree: usize },
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitError::TooFewValues { needed, got } => {
+                write!(f, "needed {needed} values to fit, got {got}")
+            }
+            FitError::NotPolynomial { max_degree } => {
+                write!(
+                    f,
+                    "the values aren't a polynomial of degree {max_degree} or less"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+/// A polynomial through values sampled at `x = 0, 1, 2, ...`, in Newton's forward difference
+/// form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Newton {
+    // The first value of the sequence, then the first of its differences, then the first of
+    // their differences and so on, up to the last that isn't always 0
+    differences: Vec<i128>,
+}
+
+impl Newton {
+    /// The lowest degree polynomial through the values, with at least one value left over to
+    /// confirm it. Anything short of that isn't taken for a pattern.
+    pub fn fit(values: &[i128]) -> Result<Newton, FitError> {
+        if values.len() < 2 {
+            return Err(FitError::TooFewValues {
+                needed: 2,
+                got: values.len(),
+            });
+        }
+        Newton::with_max_degree(values, values.len() - 2)
+    }
+
+    /// The polynomial of at most `max_degree` through the values. Any values beyond the
+    /// `max_degree + 1` needed to fit it must agree with it.
+    pub fn with_max_degree(values: &[i128], max_degree: usize) -> Result<Newton, FitError> {
+        if values.len() <= max_degree {
+            return Err(FitError::TooFewValues {
+                needed: max_degree + 1,
+                got: values.len(),
+            });
+        }
+        let mut differences = Vec::new();
+        let mut row = values.to_vec();
+        while row.iter().any(|&value| value != 0) {
+            if differences.len() > max_degree {
+                return Err(FitError::NotPolynomial { max_degree });
+            }
+            differences.push(row[0]);
+            row = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        }
+        Ok(Newton { differences })
+    }
+
+    /// The degree, which is 0 for a constant, including the zero polynomial.
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    /// The value at `x`, which can be anywhere before, among or after the samples.
+    pub fn at(&self, x: i128) -> i128 {
+        // Newton's forward formula: the sum of C(x, k) times the kth difference, where
+        // C(x, k) = C(x, k - 1) * (x - k + 1) / k is whole at every step
+        let mut value = 0;
+        let mut binomial = 1;
+        for (k, difference) in (0..).zip(&self.differences) {
+            if k > 0 {
+                binomial = binomial * (x - k + 1) / k;
+            }
+            value += binomial * difference;
+        }
+        value
+    }
+}
+
+/// The value at `x` of the lowest degree polynomial through `points`, which can be at any
+/// distinct `x`s, by Lagrange's formula.
+///
+/// Panics if two points share an `x`.
+pub fn lagrange(points: &[(i128, i128)], x: i128) -> Rational {
+    let mut value = Rational::from(0);
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut term = Rational::from(yi);
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                assert_ne!(xi, xj, "two points at x = {xi}");
+                term = term * Rational::new(x - xj, xi - xj);
+            }
+        }
+        value = value + term;
+    }
+    value
+}
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// Panics if `denominator` is 0.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert_ne!(denominator, 0, "a fraction over 0");
+        let divisor = gcd_u128(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let sign = denominator.signum();
+        Rational {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// The value if it is a whole number.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::new(value, 1)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational::new(-self.numerator, self.denominator)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics if `other` is 0.
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_newton() {
+        let triangles = Newton::fit(&[0, 1, 3, 6, 10, 15, 21]).unwrap();
+        assert_eq!(triangles.degree(), 2);
+        assert_eq!(triangles.at(7), 28);
+        assert_eq!(triangles.at(-1), 0);
+        assert_eq!(triangles.at(-3), 3);
+        assert_eq!(triangles.at(1_000_000), 500_000_500_000);
+
+        assert_eq!(Newton::fit(&[5, 5]).unwrap().degree(), 0);
+        assert_eq!(Newton::fit(&[0, 0, 0]).unwrap().at(9), 0);
+    }
+
+    #[test]
+    fn test_not_polynomial() {
+        // Doubling never settles into constant differences
+        let powers = [1, 2, 4, 8, 16, 32];
+        assert_eq!(
+            Newton::fit(&powers),
+            Err(FitError::NotPolynomial { max_degree: 4 })
+        );
+        assert_eq!(Newton::with_max_degree(&powers[..3], 2).unwrap().at(3), 7);
+        assert_eq!(
+            Newton::with_max_degree(&powers[..2], 2),
+            Err(FitError::TooFewValues { needed: 3, got: 2 })
+        );
+        assert_eq!(
+            Newton::fit(&[3]).unwrap_err().to_string(),
+            "needed 2 values to fit, got 1"
+        );
+    }
+
+    #[test]
+    fn test_lagrange() {
+        let points = [(1, 1), (3, 9), (4, 16)];
+        assert_eq!(lagrange(&points, 10).to_integer(), Some(100));
+        assert_eq!(lagrange(&points, -2).to_integer(), Some(4));
+
+        // A line through (0, 0) and (2, 1) is halfway at 1
+        let half = lagrange(&[(0, 0), (2, 1)], 1);
+        assert_eq!(half, Rational::new(1, 2));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!((half + Rational::new(-3, 4)).to_string(), "-1/4");
+        assert_eq!(
+            half / Rational::new(2, -6),
+            Rational::from(-3) / Rational::from(2)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_fit(coefficients in prop::collection::vec(-50..50_i128, 1..5), x in -20..40_i128) {
+            let polynomial = |x: i128| coefficients.iter().rev().fold(0, |value, c| value * x + c);
+            let values: Vec<_> = (0..8).map(polynomial).collect();
+            let newton = Newton::fit(&values).unwrap();
+            prop_assert!(newton.degree() < coefficients.len());
+            prop_assert_eq!(newton.at(x), polynomial(x));
+
+            let points: Vec<_> = (0..coefficients.len() as i128)
+                .map(|x| (x * 3 - 5, polynomial(x * 3 - 5)))
+                .collect();
+            prop_assert_eq!(lagrange(&points, x).to_integer(), Some(polynomial(x)));
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Graph searches over any state type, driven by a function that lists a state's neighbors.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way a search found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// Every state from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// How many states were expanded before the goal was reached.
+    pub visited: usize,
+}
+
+// Every state seen so far, numbered in the order they were found, with the cheapest known cost
+// to reach each and the state it was reached from
+struct Seen<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    cost: Vec<C>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Seen<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Seen {
+            states: vec![start.clone()],
+            index: HashMap::from([(start, 0)]),
+            cost: vec![zero],
+            parent: vec![None],
+        }
+    }
+
+    // Records reaching `state` from `parent` at `cost`, returning its number if that is cheaper
+    // than any way known before
+    fn reach(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                if cost >= self.cost[i] {
+                    return None;
+                }
+                self.cost[i] = cost;
+                self.parent[i] = Some(parent);
+                Some(i)
+            }
+            Entry::Vacant(entry) => {
+                let i = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(i);
+                self.cost.push(cost);
+                self.parent.push(Some(parent));
+                Some(i)
+            }
+        }
+    }
+
+    fn found(&self, goal: usize, visited: usize) -> Found<S, C> {
+        let mut path = vec![self.states[goal].clone()];
+        let mut at = goal;
+        while let Some(parent) = self.parent[at] {
+            path.push(self.states[parent].clone());
+            at = parent;
+        }
+        path.reverse();
+        Found {
+            cost: self.cost[goal],
+            path,
+            visited,
+        }
+    }
+}
+
+/// Breadth-first search from `start` to the nearest state that `is_goal`, where every step
+/// costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = Seen::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    let mut visited = 0;
+
+    while let Some(i) = queue.pop_front() {
+        visited += 1;
+        if is_goal(&seen.states[i]) {
+            return Some(seen.found(i, visited));
+        }
+        let cost = seen.cost[i] + 1;
+        for next in neighbors(&seen.states[i]) {
+            // The first way to a state is the shortest, so only new states are queued
+            if !seen.index.contains_key(&next) {
+                queue.extend(seen.reach(next, i, cost));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from `start` to every state reachable from it.
+pub fn bfs_distances<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(distance);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's search from `start` to the cheapest state that `is_goal`. `neighbors` lists each
+/// next state with the cost of the step to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search, which is Dijkstra's search guided by a `heuristic` estimate of the cost left to
+/// a goal. The answer is only the cheapest if the heuristic never overestimates.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut seen = Seen::new(start, C::default());
+    let mut visited = 0;
+
+    while let Some(Reverse((estimate, i))) = heap.pop() {
+        // A cheaper way to this state was queued after this one, and has been expanded already
+        if estimate > seen.cost[i] + heuristic(&seen.states[i]) {
+            continue;
+        }
+        visited += 1;
+        if is_goal(&seen.states[i]) {
+            return Some(seen.found(i, visited));
+        }
+        let cost = seen.cost[i];
+        for (next, step) in neighbors(&seen.states[i]) {
+            let estimate = cost + step + heuristic(&next);
+            if let Some(j) = seen.reach(next, i, cost + step) {
+                heap.push(Reverse((estimate, j)));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's search with a bucket queue (Dial's algorithm), which is faster than a heap when
+/// step costs are small integers.
+pub fn dial<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    // `buckets[c]` holds the states first queued at cost `c`
+    let mut buckets = vec![vec![0]];
+    let mut queued = 1;
+    let mut seen = Seen::new(start, 0);
+    let mut visited = 0;
+
+    let mut cost = 0;
+    while queued > 0 {
+        let Some(i) = buckets[cost].pop() else {
+            cost += 1;
+            continue;
+        };
+        queued -= 1;
+        if seen.cost[i] < cost {
+            continue;
+        }
+        visited += 1;
+        if is_goal(&seen.states[i]) {
+            return Some(seen.found(i, visited));
+        }
+        for (next, step) in neighbors(&seen.states[i]) {
+            let next_cost = cost + step;
+            if let Some(j) = seen.reach(next, i, next_cost) {
+                if buckets.len() <= next_cost {
+                    buckets.resize_with(next_cost + 1, Vec::new);
+                }
+                buckets[next_cost].push(j);
+                queued += 1;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    const MAZE: &str = "\
+S.#.
+.##.
+...E";
+
+    fn open(grid: &Grid<char>, position: &Position) -> Vec<Position> {
+        grid.neighbors(*position)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let found = bfs((0, 0), |p| open(&grid, p), |&p| grid[p] == 'E').unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]);
+        assert!(found.visited >= 6);
+        assert_eq!(bfs((0, 0), |p| open(&grid, p), |&p| p == (0, 2)), None);
+
+        let distances = bfs_distances((0, 0), |p| open(&grid, p));
+        assert_eq!(distances.len(), 9);
+        assert_eq!(distances[&(0, 3)], 7);
+    }
+
+    // The digits are what it costs to step onto each cell
+    const WEIGHTS: &str = "\
+1163
+1381
+2136";
+
+    fn weighted(grid: &Grid<usize>, position: &Position) -> Vec<(Position, usize)> {
+        grid.neighbors(*position)
+            .map(|next| (next, grid[next]))
+            .collect()
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = Grid::parse(WEIGHTS, |c| c.to_digit(10).map(|d| d as usize)).unwrap();
+        let goal = (2, 3);
+        let expected = [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)];
+
+        let found = dijkstra((0, 0), |p| weighted(&grid, p), |&p| p == goal).unwrap();
+        assert_eq!((found.cost, found.path.as_slice()), (13, &expected[..]));
+
+        let found = dial((0, 0), |p| weighted(&grid, p), |&p| p == goal).unwrap();
+        assert_eq!((found.cost, found.path.as_slice()), (13, &expected[..]));
+
+        // Every step costs at least 1, so the Manhattan distance never overestimates
+        let manhattan = |p: &Position| goal.0.abs_diff(p.0) + goal.1.abs_diff(p.1);
+        let found = astar((0, 0), |p| weighted(&grid, p), manhattan, |&p| p == goal).unwrap();
+        assert_eq!((found.cost, found.path.as_slice()), (13, &expected[..]));
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        let found = dijkstra(7, |_| [(8, 1)], |&n| n == 7).unwrap();
+        assert_eq!((found.cost, found.path, found.visited), (0, vec![7], 1));
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::generate::Rng;
+use crate::ParseError;
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A puzzle answer, kept as the text that gets submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(u16, u32, u64, u128, usize, i32, i64, i128, isize, String, &str);
+
+/// The outcome of running a part: `None` while it is unsolved, otherwise its answer or
+/// the reason the input couldn't be parsed.
+pub type PartResult = Option<Result<Answer, ParseError>>;
+
+/// A day's puzzle, registered with the `aoc` runner.
+///
+/// Each day implements this on a unit struct by forwarding to its own `part_1`/`part_2`
+/// functions. A part that hasn't been solved yet keeps the default and returns `None`.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn part_1(&self, _input: &str) -> PartResult {
+        None
+    }
+
+    fn part_2(&self, _input: &str) -> PartResult {
+        None
+    }
+
+    /// A random input of about `size`, roughly its number of lines or the side of its grid, or
+    /// as big as a real input without one. `None` if the day has no generator.
+    fn generate(&self, _rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+
+    /// Runs one part, locating any parse error in `input`.
+    fn solve(&self, part: Part, input: &str) -> PartResult {
+        let result = match part {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        };
+        result.map(|r| r.map_err(|e| e.locate(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> PartResult {
+            Some(Ok(input.len().into()))
+        }
+
+        fn part_2(&self, input: &str) -> PartResult {
+            Some(crate::parse::parse::<u32>(&input[1..], "a number").map(Answer::from))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Example.solve(Part::One, "abc"), Some(Ok(Answer::from("3"))));
+        assert_eq!(
+            Example.solve(Part::Two, "a12"),
+            Some(Ok(Answer::from("12")))
+        );
+        let error = Example.solve(Part::Two, "abc").unwrap().unwrap_err();
+        assert_eq!(error.position, Some((1, 2)));
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+    }
+}
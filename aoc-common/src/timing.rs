@@ -0,0 +1,98 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    // Parse time accumulated by the innermost `measure` on this thread, if one is running.
+    static PARSE: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+/// How long one call of a part took, split into input parsing and everything after it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Marks `f` as part of the parse phase of the surrounding [`measure`].
+///
+/// Outside of `measure` (e.g. in unit tests) this just runs `f`.
+pub fn parse_phase<T>(f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    PARSE.with(|parse| {
+        if let Some(total) = parse.get() {
+            parse.set(Some(total + elapsed));
+        }
+    });
+    result
+}
+
+// Puts back the surrounding `measure`'s parse time when dropped, so a panic in `f` that gets
+// caught doesn't leave this one's running on the thread
+struct Restore(Option<Duration>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        PARSE.with(|parse| parse.set(self.0));
+    }
+}
+
+/// Runs `f`, returning its result and how long was spent in and out of [`parse_phase`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Timing) {
+    let outer = Restore(PARSE.with(|parse| parse.replace(Some(Duration::ZERO))));
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let parse = PARSE.with(Cell::get).unwrap_or_default();
+    drop(outer);
+
+    let timing = Timing {
+        parse,
+        solve: elapsed.saturating_sub(parse),
+    };
+    (result, timing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_measure_splits_phases() {
+        let (result, timing) = measure(|| {
+            let n = parse_phase(|| {
+                sleep(Duration::from_millis(20));
+                2
+            });
+            sleep(Duration::from_millis(10));
+            n * 2
+        });
+
+        assert_eq!(result, 4);
+        assert!(timing.parse >= Duration::from_millis(20));
+        assert!(timing.solve >= Duration::from_millis(10));
+        assert_eq!(timing.total(), timing.parse + timing.solve);
+    }
+
+    #[test]
+    fn test_measure_restores_after_panic() {
+        let panicked = std::panic::catch_unwind(|| measure(|| parse_phase(|| panic!("bad input"))));
+        assert!(panicked.is_err());
+        assert_eq!(PARSE.with(Cell::get), None);
+    }
+
+    #[test]
+    fn test_parse_phase_outside_measure() {
+        assert_eq!(parse_phase(|| 7), 7);
+        let (_, timing) = measure(|| ());
+        assert_eq!(timing.parse, Duration::ZERO);
+    }
+}
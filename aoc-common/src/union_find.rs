@@ -0,0 +1,247 @@
+//! Disjoint sets, for grouping things into connected components as links between them turn up.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets of the numbers `0..len`, which start out each in a set of their own.
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    // An upper bound on the height of each root's tree
+    rank: Vec<u8>,
+    // The size of each root's set
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new number in a set of its own, and returns it.
+    pub fn push(&mut self) -> usize {
+        let x = self.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        self.components += 1;
+        x
+    }
+
+    /// The number that stands for the set `x` is in.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        // Path halving: point every other node on the way at its grandparent
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets `a` and `b` are in. Returns whether they were apart before.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Hang the shallower tree off the deeper one, so trees stay shallow
+        let (root, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set `x` is in.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// How many sets there are.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every set, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+/// A [`UnionFind`] over keys of any kind, such as node names, which are numbered as they are
+/// first seen.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    ids: HashMap<K, usize>,
+    sets: UnionFind,
+}
+
+impl<K: Hash + Eq> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        KeyedUnionFind {
+            ids: HashMap::new(),
+            sets: UnionFind::default(),
+        }
+    }
+
+    /// The number of `key`, adding it in a set of its own if it is new.
+    pub fn insert(&mut self, key: K) -> usize {
+        *self.ids.entry(key).or_insert_with(|| self.sets.push())
+    }
+
+    /// The number `key` was given, if it has been seen.
+    pub fn id(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    /// Merges the sets `a` and `b` are in, adding either if it is new. Returns whether they
+    /// were apart before.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether both keys have been seen and are in the same set.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.id(a), self.id(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// The size of the set `key` is in, if it has been seen.
+    pub fn size_of(&mut self, key: &K) -> Option<usize> {
+        let id = self.id(key)?;
+        Some(self.sets.size_of(id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+
+    pub fn components(&self) -> usize {
+        self.sets.components()
+    }
+
+    /// The size of every set, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.sets.component_sizes()
+    }
+}
+
+impl<K: Hash + Eq> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        KeyedUnionFind::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(4, 5));
+        assert!(sets.connected(0, 2) && !sets.connected(0, 3));
+        assert_eq!(sets.size_of(2), 3);
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.component_sizes(), [3, 2, 1]);
+
+        let x = sets.push();
+        assert_eq!((x, sets.components()), (6, 4));
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut sets = KeyedUnionFind::new();
+        sets.union("jqt", "rhn");
+        sets.union("rhn", "xhk");
+        sets.insert("cmg");
+        assert_eq!(sets.len(), 4);
+        assert!(sets.connected(&"jqt", &"xhk"));
+        assert!(!sets.connected(&"jqt", &"cmg"));
+        assert!(!sets.connected(&"jqt", &"nvd"));
+        assert_eq!(sets.size_of(&"xhk"), Some(3));
+        assert_eq!(sets.size_of(&"nvd"), None);
+        assert_eq!(sets.component_sizes(), [3, 1]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_components(edges in prop::collection::vec((0..20_usize, 0..20_usize), 0..30)) {
+            let mut sets = UnionFind::new(20);
+            for &(a, b) in &edges {
+                sets.union(a, b);
+            }
+
+            // Label each node with the smallest node it can reach, spreading labels until
+            // nothing changes
+            let mut label: Vec<usize> = (0..20).collect();
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for &(a, b) in &edges {
+                    let smallest = label[a].min(label[b]);
+                    if label[a] != smallest || label[b] != smallest {
+                        (label[a], label[b]) = (smallest, smallest);
+                        changed = true;
+                    }
+                }
+            }
+
+            let mut sizes = vec![0; 20];
+            for &l in &label {
+                sizes[l] += 1;
+            }
+            let mut expected: Vec<usize> = sizes.into_iter().filter(|&size| size > 0).collect();
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            prop_assert_eq!(sets.components(), expected.len());
+            prop_assert_eq!(sets.component_sizes(), expected);
+            for a in 0..20 {
+                for b in 0..20 {
+                    prop_assert_eq!(sets.connected(a, b), label[a] == label[b]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Drawing a solution's state as it runs. Solutions hand each state worth seeing to [`frame`],
+//! which does nothing unless the runner is [recording](record) them.
+
+use std::cell::RefCell;
+
+use crate::grid::Grid;
+
+thread_local! {
+    // The frames drawn so far by the innermost `record` on this thread, if one is running.
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// A color as its red, green and blue parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(96, 96, 96);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const ORANGE: Rgb = Rgb(240, 140, 30);
+    pub const YELLOW: Rgb = Rgb(250, 220, 60);
+    pub const GREEN: Rgb = Rgb(90, 200, 80);
+    pub const BLUE: Rgb = Rgb(60, 120, 230);
+
+    /// The color `t` of the way from `self` to `other`, where `t` is clamped to `0.0..=1.0`.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// One cell of a frame: a character for the terminal, in a color used by every output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Pixel {
+    pub fn new(glyph: char, color: Rgb) -> Self {
+        Pixel { glyph, color }
+    }
+}
+
+/// A state that can be drawn as a frame.
+pub trait Visualize {
+    fn draw(&self) -> Grid<Pixel>;
+}
+
+/// A kind of cell that can be drawn on its own, which makes a [`Grid`] of it [`Visualize`].
+pub trait ToPixel {
+    fn to_pixel(&self) -> Pixel;
+}
+
+impl<T: ToPixel> Visualize for Grid<T> {
+    fn draw(&self) -> Grid<Pixel> {
+        self.map(ToPixel::to_pixel)
+    }
+}
+
+impl ToPixel for Pixel {
+    fn to_pixel(&self) -> Pixel {
+        *self
+    }
+}
+
+/// The frames drawn while [`record`] ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub frames: Vec<Grid<Pixel>>,
+    /// How many frames were drawn after the limit was reached, and so weren't kept.
+    pub dropped: usize,
+    limit: usize,
+}
+
+/// Whether a [`record`] is running, for states that are only worth building to draw.
+pub fn is_recording() -> bool {
+    RECORDING.with(|recording| recording.borrow().is_some())
+}
+
+/// Draws `state` as the next frame of the surrounding [`record`].
+///
+/// Outside of `record` this does nothing, and `state` is never drawn.
+pub fn frame(state: &(impl Visualize + ?Sized)) {
+    RECORDING.with(|recording| {
+        if let Some(recording) = recording.borrow_mut().as_mut() {
+            if recording.frames.len() < recording.limit {
+                recording.frames.push(state.draw());
+            } else {
+                recording.dropped += 1;
+            }
+        }
+    });
+}
+
+// Puts back the surrounding `record`'s frames when dropped, so a panic in `f` that gets caught
+// doesn't leave this one recording on the thread
+struct Restore(Option<Recording>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        RECORDING.with(|recording| *recording.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `f`, returning its result and the first `limit` frames it drew.
+pub fn record<T>(limit: usize, f: impl FnOnce() -> T) -> (T, Recording) {
+    let empty = Recording {
+        limit,
+        ..Recording::default()
+    };
+    let outer = Restore(RECORDING.with(|recording| recording.replace(Some(empty))));
+    let result = f();
+    let recording = RECORDING.with(|recording| recording.borrow_mut().take());
+    drop(outer);
+    (result, recording.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct Lit(bool);
+
+    impl ToPixel for Lit {
+        fn to_pixel(&self) -> Pixel {
+            if self.0 {
+                Pixel::new('#', Rgb::YELLOW)
+            } else {
+                Pixel::new('.', Rgb::GRAY)
+            }
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut grid = Grid::filled(2, 1, Lit(false));
+        let (result, recording) = record(2, || {
+            for column in 0..2 {
+                grid[(0, column)] = Lit(true);
+                frame(&grid);
+            }
+            frame(&grid);
+            is_recording()
+        });
+
+        assert!(result);
+        assert_eq!(recording.dropped, 1);
+        let glyphs: Vec<String> = recording
+            .frames
+            .iter()
+            .map(|frame| frame.rows().flatten().map(|pixel| pixel.glyph).collect())
+            .collect();
+        assert_eq!(glyphs, ["#.", "##"]);
+        assert_eq!(recording.frames[1][(0, 1)].color, Rgb::YELLOW);
+    }
+
+    #[test]
+    fn test_record_stops_after_panic() {
+        let panicked = std::panic::catch_unwind(|| record(1, || panic!("bad input")));
+        assert!(panicked.is_err());
+        assert!(!is_recording());
+    }
+
+    #[test]
+    fn test_frame_outside_record() {
+        struct Unreachable;
+
+        impl Visualize for Unreachable {
+            fn draw(&self) -> Grid<Pixel> {
+                panic!("drawn without a recording")
+            }
+        }
+
+        assert!(!is_recording());
+        frame(&Unreachable);
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::BLACK.mix(Rgb::RED, 2.0), Rgb::RED);
+    }
+}
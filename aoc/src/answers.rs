@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Write as _};
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc_common::{Answer, ParseError, Part, PartResult, Solution};
+
+use crate::run::panic_message;
+
+const HEADER: &str = "# year day part answer, or `!answer note` for an answer the site rejected\n";
+
+#[derive(Debug, Default, PartialEq)]
+struct Entry {
+    correct: Option<String>,
+    // Rejected answers with the site's hint, e.g. "too high"
+    wrong: Vec<(String, String)>,
+}
+
+/// Known-good answers for our real inputs, plus the ones the site told us were wrong,
+/// keyed by year, day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u16, u8, u8), Entry>,
+}
+
+/// `answers.txt` at the root of the repository.
+pub fn default_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.txt")
+}
+
+impl Answers {
+    // One `year day part answer` entry per line. Blank lines and `#` comments are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let (Some(year), Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!(
+                    "line {}: expected `year day part answer`, got {line:?}",
+                    idx + 1
+                ));
+            };
+            let year = number(idx, year, "year")?;
+            let day = number(idx, day, "day")?;
+            let part = Part::from_number(number(idx, part, "part")?)
+                .ok_or_else(|| format!("line {}: part must be 1 or 2", idx + 1))?;
+            let note = fields.collect::<Vec<_>>().join(" ");
+
+            match answer.strip_prefix('!') {
+                Some(wrong) => answers.insert_wrong(year, day, part, wrong.to_string(), note),
+                None if note.is_empty() => answers.insert(year, day, part, answer.to_string()),
+                None => {
+                    return Err(format!(
+                        "line {}: unexpected {note:?} after the answer",
+                        idx + 1
+                    ))
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    // A missing file is an empty registry, so `--record` can create it.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("could not write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.entries
+            .get(&(year, day, part.number()))
+            .and_then(|entry| entry.correct.as_deref())
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: Part, answer: String) {
+        self.entries
+            .entry((year, day, part.number()))
+            .or_default()
+            .correct = Some(answer);
+    }
+
+    /// How a part's result compares with the answer on record. `None` for a part that is
+    /// neither solved nor recorded.
+    pub fn compare(&self, year: u16, day: u8, part: Part, result: &PartResult) -> Option<Outcome> {
+        let answer = match result {
+            Some(Ok(answer)) => Some(answer),
+            Some(Err(error)) => {
+                return Some(Outcome::Failed {
+                    error: error.clone(),
+                })
+            }
+            None => None,
+        };
+        let outcome = match (answer, self.get(year, day, part)) {
+            (None, None) => return None,
+            (Some(_), None) => Outcome::Unrecorded,
+            (None, Some(expected)) => Outcome::Missing {
+                expected: expected.to_string(),
+            },
+            (Some(answer), Some(expected)) if answer.as_str() == expected => Outcome::Correct,
+            (Some(_), Some(expected)) => Outcome::Wrong {
+                expected: expected.to_string(),
+            },
+        };
+        Some(outcome)
+    }
+
+    /// The hint the site gave when `answer` was rejected, if it was.
+    pub fn wrong(&self, year: u16, day: u8, part: Part, answer: &str) -> Option<&str> {
+        self.entries
+            .get(&(year, day, part.number()))?
+            .wrong
+            .iter()
+            .find(|(wrong, _)| wrong == answer)
+            .map(|(_, note)| note.as_str())
+    }
+
+    pub fn insert_wrong(&mut self, year: u16, day: u8, part: Part, answer: String, note: String) {
+        let entry = self.entries.entry((year, day, part.number())).or_default();
+        if !entry.wrong.iter().any(|(wrong, _)| *wrong == answer) {
+            entry.wrong.push((answer, note));
+        }
+    }
+}
+
+fn number<T: FromStr>(idx: usize, field: &str, name: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|e| format!("line {}: invalid {name} {field:?}: {e}", idx + 1))
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::from(HEADER);
+        for ((year, day, part), entry) in &self.entries {
+            if let Some(answer) = &entry.correct {
+                writeln!(out, "{year} {day} {part} {answer}")?;
+            }
+            for (answer, note) in &entry.wrong {
+                writeln!(out, "{year} {day} {part} !{answer} {note}")?;
+            }
+        }
+        let out: String = out.lines().map(|l| format!("{}\n", l.trim_end())).collect();
+        f.write_str(&out)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong { expected: String },
+    // The part has an answer on record but the solution no longer returns one
+    Missing { expected: String },
+    // The input couldn't be parsed
+    Failed { error: ParseError },
+    // The part panicked, with this message
+    Panicked { message: String },
+    Unrecorded,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub outcome: Outcome,
+}
+
+/// Runs both parts and compares them against the registry. Parts that are
+/// neither solved nor recorded are left out, and parts that panic always fail.
+pub fn check(solution: &dyn Solution, input: &str, answers: &Answers) -> Vec<Check> {
+    Part::BOTH
+        .iter()
+        .filter_map(|&part| {
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input)))
+            {
+                Ok(result) => result,
+                Err(payload) => {
+                    return Some(Check {
+                        part,
+                        answer: None,
+                        outcome: Outcome::Panicked {
+                            message: panic_message(payload.as_ref()),
+                        },
+                    })
+                }
+            };
+            let outcome = answers.compare(solution.year(), solution.day(), part, &result)?;
+            Some(Check {
+                part,
+                answer: result.and_then(Result::ok),
+                outcome,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixed;
+
+    impl Solution for Fixed {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn part_1(&self, input: &str) -> PartResult {
+            Some(Ok(input.len().into()))
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let answers = Answers::parse(
+            "# year day part answer\n\n2023 17 2 1157\n2023 17 1 936\n2023 5 1 abc\n2023 5 2 !12 too low\n2023 5 2 !90\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2023, 17, Part::One), Some("936"));
+        assert_eq!(answers.get(2023, 5, Part::One), Some("abc"));
+        assert_eq!(answers.get(2023, 17, Part::Two), Some("1157"));
+        assert_eq!(answers.get(2023, 5, Part::Two), None);
+        assert_eq!(answers.wrong(2023, 5, Part::Two, "12"), Some("too low"));
+        assert_eq!(answers.wrong(2023, 5, Part::Two, "90"), Some(""));
+        assert_eq!(answers.wrong(2023, 5, Part::Two, "13"), None);
+
+        assert_eq!(
+            answers.to_string().lines().skip(1).collect::<Vec<_>>(),
+            [
+                "2023 5 1 abc",
+                "2023 5 2 !12 too low",
+                "2023 5 2 !90",
+                "2023 17 1 936",
+                "2023 17 2 1157"
+            ]
+        );
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("2023 1 1 5\n2023 1\n").unwrap_err(),
+            "line 2: expected `year day part answer`, got \"2023 1\""
+        );
+        assert_eq!(
+            Answers::parse("2023 1 3 5").unwrap_err(),
+            "line 1: part must be 1 or 2"
+        );
+        assert_eq!(
+            Answers::parse("2023 1 1 5 6").unwrap_err(),
+            "line 1: unexpected \"6\" after the answer"
+        );
+        assert!(Answers::parse("twenty 1 1 5")
+            .unwrap_err()
+            .starts_with("line 1: invalid year"));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        let outcomes = |answers: &Answers| -> Vec<Outcome> {
+            check(&Fixed, "abcd", answers)
+                .into_iter()
+                .map(|c| c.outcome)
+                .collect()
+        };
+        assert_eq!(outcomes(&answers), [Outcome::Unrecorded]);
+
+        answers.insert(2023, 3, Part::One, "4".to_string());
+        assert_eq!(outcomes(&answers), [Outcome::Correct]);
+
+        answers.insert(2023, 3, Part::One, "5".to_string());
+        answers.insert(2023, 3, Part::Two, "6".to_string());
+        assert_eq!(
+            outcomes(&answers),
+            [
+                Outcome::Wrong {
+                    expected: "5".to_string()
+                },
+                Outcome::Missing {
+                    expected: "6".to_string()
+                }
+            ]
+        );
+    }
+
+    // Panics on part 1 and answers part 2
+    struct Panicky;
+
+    impl Solution for Panicky {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn part_1(&self, input: &str) -> PartResult {
+            panic!("can't handle {input:?}");
+        }
+
+        fn part_2(&self, input: &str) -> PartResult {
+            Some(Ok(input.len().into()))
+        }
+    }
+
+    #[test]
+    fn test_check_panics() {
+        let mut answers = Answers::default();
+        answers.insert(2023, 2, Part::Two, "2".to_string());
+        let outcomes: Vec<_> = check(&Panicky, "hi", &answers)
+            .into_iter()
+            .map(|c| (c.part, c.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                (
+                    Part::One,
+                    Outcome::Panicked {
+                        message: "can't handle \"hi\"".to_string()
+                    }
+                ),
+                (Part::Two, Outcome::Correct)
+            ]
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_common::{measure, ParseError, Part, Solution, Timing};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::run::panic_message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Summary of one phase over every run, in nanoseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(samples: impl IntoIterator<Item = Duration>) -> Self {
+        let mut nanos: Vec<u64> = samples.into_iter().map(|d| d.as_nanos() as u64).collect();
+        if nanos.is_empty() {
+            return Stats::default();
+        }
+        nanos.sort_unstable();
+
+        let mid = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[mid - 1] + nanos[mid]) / 2
+        } else {
+            nanos[mid]
+        };
+        Stats {
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+impl BenchResult {
+    fn from_timings(year: u16, day: u8, part: Part, timings: &[Timing]) -> Self {
+        BenchResult {
+            year,
+            day,
+            part: part.number(),
+            runs: timings.len(),
+            parse: Stats::from_samples(timings.iter().map(|t| t.parse)),
+            solve: Stats::from_samples(timings.iter().map(|t| t.solve)),
+            total: Stats::from_samples(timings.iter().map(Timing::total)),
+        }
+    }
+
+    fn key(&self) -> (u16, u8, u8) {
+        (self.year, self.day, self.part)
+    }
+}
+
+/// Why a part couldn't be benchmarked.
+#[derive(Debug)]
+pub enum Failure {
+    Parse(ParseError),
+    /// What the part panicked with.
+    Panic(String),
+}
+
+/// Runs each solved part `runs` times. Unsolved parts are left out, and parts that fail to
+/// parse the input or panic are returned with why instead of timings.
+pub fn bench_day(
+    solution: &dyn Solution,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> (Vec<BenchResult>, Vec<(Part, Failure)>) {
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+    for &part in parts {
+        let mut timings = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let (answer, timing) =
+                measure(|| panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input))));
+            match answer {
+                Ok(Some(Ok(_))) => timings.push(timing),
+                Ok(Some(Err(e))) => {
+                    errors.push((part, Failure::Parse(e)));
+                    break;
+                }
+                Ok(None) => break,
+                Err(payload) => {
+                    errors.push((part, Failure::Panic(panic_message(payload.as_ref()))));
+                    break;
+                }
+            }
+        }
+        if !timings.is_empty() {
+            results.push(BenchResult::from_timings(
+                solution.year(),
+                solution.day(),
+                part,
+                &timings,
+            ));
+        }
+    }
+    (results, errors)
+}
+
+pub fn write(results: &[BenchResult], format: Format, out: &mut impl Write) -> io::Result<()> {
+    match format {
+        Format::Text => write_text(results, out),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, results)?;
+            writeln!(out)
+        }
+        Format::Csv => write_csv(results, out),
+    }
+}
+
+fn write_text(results: &[BenchResult], out: &mut impl Write) -> io::Result<()> {
+    let ns = |n: u64| format!("{:.2?}", Duration::from_nanos(n));
+    writeln!(
+        out,
+        "{:>4} {:>4} {:>4} {:>12} {:>12} {:>12} {:>12}",
+        "day", "part", "runs", "parse", "solve", "median", "min"
+    )?;
+    for r in results {
+        writeln!(
+            out,
+            "{:>4} {:>4} {:>4} {:>12} {:>12} {:>12} {:>12}",
+            r.day,
+            r.part,
+            r.runs,
+            ns(r.parse.median_ns),
+            ns(r.solve.median_ns),
+            ns(r.total.median_ns),
+            ns(r.total.min_ns),
+        )?;
+    }
+    Ok(())
+}
+
+fn write_csv(results: &[BenchResult], out: &mut impl Write) -> io::Result<()> {
+    let mut header = ["year", "day", "part", "runs"].join(",");
+    for phase in ["parse", "solve", "total"] {
+        for stat in ["min_ns", "median_ns", "mean_ns", "max_ns"] {
+            header.push_str(&format!(",{phase}_{stat}"));
+        }
+    }
+    writeln!(out, "{header}")?;
+
+    for r in results {
+        write!(out, "{},{},{},{}", r.year, r.day, r.part, r.runs)?;
+        for stats in [r.parse, r.solve, r.total] {
+            write!(
+                out,
+                ",{},{},{},{}",
+                stats.min_ns, stats.median_ns, stats.mean_ns, stats.max_ns
+            )?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Reads results previously written with `--format json`.
+pub fn load_baseline(path: &Path) -> Result<Vec<BenchResult>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("could not read baseline {}: {e}", path.display()))?;
+    serde_json::from_str(&text).map_err(|e| format!("invalid baseline {}: {e}", path.display()))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: Option<u64>,
+    pub current_ns: u64,
+}
+
+impl Comparison {
+    /// Relative change of the median total time, in percent.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline_ns
+            .filter(|&base| base > 0)
+            .map(|base| (self.current_ns as f64 - base as f64) / base as f64 * 100.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(current: &[BenchResult], baseline: &[BenchResult]) -> Vec<Comparison> {
+    let baseline: HashMap<_, _> = baseline.iter().map(|r| (r.key(), r)).collect();
+    current
+        .iter()
+        .map(|r| Comparison {
+            year: r.year,
+            day: r.day,
+            part: r.part,
+            baseline_ns: baseline.get(&r.key()).map(|b| b.total.median_ns),
+            current_ns: r.total.median_ns,
+        })
+        .collect()
+}
+
+/// Prints one line per part and returns how many got slower than `threshold` percent.
+pub fn print_comparison(comparisons: &[Comparison], threshold: f64) -> usize {
+    let ns = |n: u64| format!("{:.2?}", Duration::from_nanos(n));
+    let mut regressions = 0;
+    for c in comparisons {
+        let label = format!("{} day {} part {}", c.year, c.day, c.part);
+        match (c.baseline_ns, c.change()) {
+            (Some(base), Some(change)) => {
+                let flag = if c.is_regression(threshold) {
+                    regressions += 1;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                eprintln!(
+                    "{label}: {} -> {} ({change:+.1}%){flag}",
+                    ns(base),
+                    ns(c.current_ns)
+                );
+            }
+            _ => eprintln!("{label}: {} (not in baseline)", ns(c.current_ns)),
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, median_ns: u64) -> BenchResult {
+        let stats = Stats {
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            max_ns: median_ns,
+        };
+        BenchResult {
+            year: 2023,
+            day,
+            part: 1,
+            runs: 1,
+            parse: Stats::default(),
+            solve: stats,
+            total: stats,
+        }
+    }
+
+    // Answers part 1 with the input's length, and can't parse anything for part 2
+    struct Half;
+
+    impl Solution for Half {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            4
+        }
+
+        fn part_1(&self, input: &str) -> aoc_common::PartResult {
+            Some(Ok(input.len().into()))
+        }
+
+        fn part_2(&self, input: &str) -> aoc_common::PartResult {
+            Some(Err(ParseError::new(input, "expected nothing")))
+        }
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let (results, errors) = bench_day(&Half, "abc", &Part::BOTH, 3);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            (results[0].day, results[0].part, results[0].runs),
+            (4, 1, 3)
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, Part::Two);
+        assert!(matches!(&errors[0].1, Failure::Parse(e) if e.message == "expected nothing"));
+    }
+
+    // Panics on part 1 and answers part 2
+    struct Panicky;
+
+    impl Solution for Panicky {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn part_1(&self, input: &str) -> aoc_common::PartResult {
+            panic!("can't handle {input:?}");
+        }
+
+        fn part_2(&self, input: &str) -> aoc_common::PartResult {
+            Some(Ok(input.len().into()))
+        }
+    }
+
+    #[test]
+    fn test_bench_day_panics() {
+        let (results, errors) = bench_day(&Panicky, "hi", &Part::BOTH, 2);
+        assert_eq!((results.len(), results[0].part), (1, 2));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0].1, Failure::Panic(m) if m == "can't handle \"hi\""));
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples([4, 1, 3, 2].map(Duration::from_nanos));
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 2,
+                mean_ns: 2,
+                max_ns: 4
+            }
+        );
+        assert_eq!(
+            Stats::from_samples([5, 1, 9].map(Duration::from_nanos)).median_ns,
+            5
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let results = vec![result(1, 100), result(2, 200)];
+        let mut out = Vec::new();
+        write(&results, Format::Json, &mut out).unwrap();
+        let parsed: Vec<BenchResult> = serde_json::from_slice(&out).unwrap();
+        assert_eq!(parsed, results);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        write(&[result(3, 50)], Format::Csv, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("year,day,part,runs,parse_min_ns,"));
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[1].starts_with("2023,3,1,1,0,0,0,0,50,"));
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let baseline = vec![result(1, 100), result(2, 100)];
+        let current = vec![result(1, 105), result(2, 150), result(3, 10)];
+        let comparisons = compare(&current, &baseline);
+
+        assert!(!comparisons[0].is_regression(10.0));
+        assert!(comparisons[1].is_regression(10.0));
+        assert_eq!(comparisons[1].change(), Some(50.0));
+        assert_eq!(comparisons[2].baseline_ns, None);
+        assert!(!comparisons[2].is_regression(10.0));
+    }
+}
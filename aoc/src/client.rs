@@ -0,0 +1,291 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::Part;
+
+#[cfg(test)]
+pub mod mock;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Shortest gap we leave between two requests to the site, across runs of the tool.
+pub const MIN_DELAY: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP layer, so the client can be pointed at something other than the real site.
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String>;
+}
+
+pub struct HttpTransport {
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    pub fn new() -> Self {
+        HttpTransport {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn finish(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e.to_string()),
+        };
+        let status = response.status();
+        let body = response.into_string().map_err(|e| e.to_string())?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        Self::finish(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String> {
+        Self::finish(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+/// Makes sure consecutive requests are at least `min_delay` apart, remembering the
+/// time of the last one in a file so separate invocations are throttled too.
+pub struct Throttle {
+    stamp: PathBuf,
+    min_delay: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: PathBuf, min_delay: Duration) -> Self {
+        Throttle { stamp, min_delay }
+    }
+
+    pub fn wait(&self) -> Result<(), String> {
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(remaining) = last.and_then(|last| (last + self.min_delay).checked_sub(now())) {
+            thread::sleep(remaining.min(self.min_delay));
+        }
+
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        }
+        fs::write(&self.stamp, now().as_millis().to_string())
+            .map_err(|e| format!("could not write {}: {e}", self.stamp.display()))
+    }
+}
+
+/// The session cookie: `$AOC_SESSION`, else the contents of `~/.config/aoc/session`.
+pub fn session_token() -> Result<String, String> {
+    if let Ok(token) = env::var("AOC_SESSION") {
+        return Ok(token.trim().to_string());
+    }
+    let path = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc").join("session"))
+        .ok_or("no session token: set AOC_SESSION")?;
+    fs::read_to_string(&path)
+        .map(|token| token.trim().to_string())
+        .map_err(|e| {
+            format!(
+                "no session token: set AOC_SESSION or write it to {} ({e})",
+                path.display()
+            )
+        })
+}
+
+pub struct Client<T> {
+    transport: T,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T, base_url: &str, session: String, throttle: Throttle) -> Self {
+        Client {
+            transport,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle,
+        }
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.throttle.wait()?;
+        let response = self
+            .transport
+            .get(&format!("{}/input", self.day_url(year, day)), &self.session)?;
+        match response.status {
+            200 => Ok(response.body),
+            400 | 500 => Err(format!(
+                "the site rejected the session token (HTTP {}), it has probably expired",
+                response.status
+            )),
+            404 => Err(format!("{year} day {day} is not available yet")),
+            status => Err(format!(
+                "unexpected HTTP {status}: {}",
+                response.body.trim()
+            )),
+        }
+    }
+
+    /// Posts an answer and returns the page the site replied with.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, String> {
+        self.throttle.wait()?;
+        let level = part.number().to_string();
+        let response = self.transport.post_form(
+            &format!("{}/answer", self.day_url(year, day)),
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        match response.status {
+            200 => Ok(response.body),
+            400 | 500 => Err(format!(
+                "the site rejected the session token (HTTP {}), it has probably expired",
+                response.status
+            )),
+            status => Err(format!(
+                "unexpected HTTP {status}: {}",
+                response.body.trim()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockServer;
+    use std::time::Instant;
+
+    fn client(server: &MockServer, name: &str) -> Client<HttpTransport> {
+        let stamp = env::temp_dir().join(format!("aoc-client-{}-{name}", std::process::id()));
+        let _ = fs::remove_file(&stamp);
+        Client::new(
+            HttpTransport::new(),
+            &server.url(),
+            "abc123".to_string(),
+            Throttle::new(stamp, Duration::ZERO),
+        )
+    }
+
+    #[test]
+    fn test_input() {
+        let server = MockServer::start(vec![(200, "1 2 3\n4 5 6\n")]);
+        let input = client(&server, "input").input(2023, 22).unwrap();
+        assert_eq!(input, "1 2 3\n4 5 6\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/22/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert!(requests[0]
+            .header("user-agent")
+            .unwrap()
+            .starts_with("aoc-runner/"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+        ]);
+        let client = client(&server, "errors");
+        assert_eq!(
+            client.input(2023, 25).unwrap_err(),
+            "2023 day 25 is not available yet"
+        );
+        assert!(client.input(2023, 1).unwrap_err().contains("session token"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, "<main>ok</main>")]);
+        let page = client(&server, "submit")
+            .submit(2023, 17, Part::Two, "1157")
+            .unwrap();
+        assert_eq!(page, "<main>ok</main>");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/17/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1157");
+        assert_eq!(
+            requests[0].header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+    }
+
+    #[test]
+    fn test_throttle() {
+        let stamp = env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        let _ = fs::remove_file(&stamp);
+        let throttle = Throttle::new(stamp.clone(), Duration::from_millis(200));
+
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+        throttle.wait().unwrap();
+        // The stamp only keeps whole milliseconds
+        assert!(start.elapsed() >= Duration::from_millis(195));
+        fs::remove_file(stamp).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where puzzle input comes from for a single run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The user cache, falling back to a local copy in the day's directory.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a file path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Default,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> io::Result<String> {
+        match self {
+            Source::Default => load_default(cache_dir().as_deref(), year, day),
+            Source::Path(path) => read(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The root of the repository, where the workspace `Cargo.toml` is.
+pub fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Where a day's puzzle input can be kept in its directory, e.g. `2023/day17/input.txt`. Git
+/// ignores these, as inputs mustn't be shared.
+pub fn repo_path(year: u16, day: u8) -> PathBuf {
+    repo_root()
+        .join(year.to_string())
+        .join(format!("day{day}"))
+        .join("input.txt")
+}
+
+/// The per-user input cache: `$AOC_CACHE_DIR`, else `$XDG_CACHE_HOME/aoc`, else `~/.cache/aoc`.
+pub fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("aoc"));
+    }
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache").join("aoc"))
+}
+
+/// Where a day's input lives inside the cache, e.g. `~/.cache/aoc/2023/day17.txt`.
+pub fn cache_path(cache: &Path, year: u16, day: u8) -> PathBuf {
+    cache.join(year.to_string()).join(format!("day{day}.txt"))
+}
+
+fn load_default(cache: Option<&Path>, year: u16, day: u8) -> io::Result<String> {
+    if let Some(cache) = cache {
+        let path = cache_path(cache, year, day);
+        if path.is_file() {
+            return read(&path);
+        }
+    }
+    read(&repo_path(year, day))
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("could not read input {}: {e}", path.display()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Default);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("other.txt")),
+            Source::Path(PathBuf::from("other.txt"))
+        );
+    }
+
+    #[test]
+    fn test_cache_is_preferred() {
+        let cache = scratch_dir("preferred");
+        let path = cache_path(&cache, 1999, 1);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "cached").unwrap();
+
+        assert_eq!(load_default(Some(&cache), 1999, 1).unwrap(), "cached");
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_missing_everywhere_names_repo_path() {
+        let cache = scratch_dir("missing");
+        let err = load_default(Some(&cache), 1999, 2).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("day2"));
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_path_source() {
+        let dir = scratch_dir("path");
+        let path = dir.join("someone-else.txt");
+        fs::write(&path, "1\n2\n").unwrap();
+
+        assert_eq!(Source::Path(path).load(2023, 1).unwrap(), "1\n2\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
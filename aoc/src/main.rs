@@ -0,0 +1,596 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_common::generate::Rng;
+use aoc_common::{Answer, Part, Solution};
+use clap::{Args, Parser, Subcommand};
+
+mod answers;
+mod bench;
+mod client;
+mod input;
+mod registry;
+mod run;
+mod scaffold;
+mod submit;
+mod trace;
+mod visualize;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every registered day of a year with --all
+    Run(RunArgs),
+    /// Time solutions over repeated runs
+    Bench(BenchArgs),
+    /// Check every solved part against the recorded answers for our inputs
+    Verify(VerifyArgs),
+    /// Download a day's input into the input cache
+    Fetch(FetchArgs),
+    /// Submit an answer and record what the site said about it
+    Submit(SubmitArgs),
+    /// Create the crate for a new day and register it with the workspace and the runner
+    New(NewArgs),
+    /// Make a random input in the format of a day's puzzle
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    year: u16,
+
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every registered day of the year
+    #[arg(long)]
+    all: bool,
+
+    /// Read input from this file, or from stdin with `-`, instead of the input cache
+    #[arg(short, long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<String>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    #[command(flatten)]
+    visualize: VisualizeArgs,
+
+    /// Print the solutions' trace events that pass this filter, like `day20=debug`
+    #[arg(long, value_name = "FILTER")]
+    trace: Option<String>,
+
+    /// Write the trace events to this file instead of stderr
+    #[arg(long, value_name = "PATH", requires = "trace")]
+    trace_file: Option<PathBuf>,
+
+    /// Run this many days at once with --all
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        // exactly one of a day and --all is given, and --all's default counts for `requires`
+        conflicts_with = "day",
+        value_parser = clap::value_parser!(u16).range(1..)
+    )]
+    jobs: u16,
+
+    /// Answers file to check --all runs against
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value_os_t = answers::default_path(),
+        conflicts_with = "day"
+    )]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct VisualizeArgs {
+    /// Show or save the states each part draws as it runs
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    visualize: Option<visualize::Format>,
+
+    /// Directory to save images in
+    #[arg(long, value_name = "DIR", default_value = "visualizations")]
+    out: PathBuf,
+
+    /// Pixels per grid cell in images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=64))]
+    scale: u16,
+
+    /// Frames per second in the terminal and in GIFs
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=100))]
+    fps: u32,
+
+    /// Keep at most this many frames of each part
+    #[arg(long, default_value_t = 1000)]
+    max_frames: usize,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How many times to run each part
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    #[arg(short, long, value_enum, default_value_t = bench::Format::Text)]
+    format: bench::Format,
+
+    /// Compare against results saved with `--format json`
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Percent slowdown of the median over the baseline that counts as a regression
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this year
+    year: Option<u16>,
+
+    /// Only verify this day
+    #[arg(requires = "year")]
+    day: Option<u8>,
+
+    /// Answers file to check against
+    #[arg(long, value_name = "PATH", default_value_os_t = answers::default_path())]
+    answers: PathBuf,
+
+    /// Save answers for parts that don't have one on record yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    year: u16,
+    day: u8,
+
+    /// Download again even if the input is already cached
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u16,
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Submit this instead of running the solution on the cached input
+    answer: Option<String>,
+
+    /// Answers file to record the outcome in
+    #[arg(long, value_name = "PATH", default_value_os_t = answers::default_path())]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    year: u16,
+    day: u8,
+
+    /// Seed to make the input from, or one from the clock, printed to stderr
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Roughly the number of lines, or the side of the grid; as big as a real input without it
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Write the input to this file instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    out: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Generate(args) => generate(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl Selection {
+    fn parts(&self) -> Vec<Part> {
+        match self.part.and_then(Part::from_number) {
+            Some(part) => vec![part],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+
+    // Every selected day with its input. With --all, days without an input are skipped.
+    fn load(&self) -> Result<Vec<(&'static dyn Solution, String)>, String> {
+        if self.all {
+            let mut days = Vec::new();
+            for solution in registry::for_year(self.year) {
+                match input::Source::Default.load(solution.year(), solution.day()) {
+                    Ok(input) => days.push((solution, input)),
+                    Err(e) => eprintln!("skipping day {}: {e}", solution.day()),
+                }
+            }
+            if days.is_empty() {
+                return Err(format!("no days ran for {}", self.year));
+            }
+            return Ok(days);
+        }
+
+        let day = self.day.expect("clap requires a day without --all");
+        let solution = registry::find(self.year, day)
+            .ok_or_else(|| format!("{} day {day} is not registered", self.year))?;
+        let input = input::Source::from_arg(self.input.as_deref())
+            .load(self.year, day)
+            .map_err(|e| e.to_string())?;
+        Ok(vec![(solution, input)])
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if let Some(filter) = &args.trace {
+        trace::init(filter, args.trace_file.as_deref())?;
+    }
+    let parts = args.selection.parts();
+    let days = args.selection.load()?;
+    let max_frames = args.visualize.visualize.map(|_| args.visualize.max_frames);
+    let start = Instant::now();
+    let reports = run::run_days(&days, &parts, args.jobs.into(), max_frames);
+    let wall = start.elapsed();
+
+    let mut failed = 0;
+    if args.selection.all {
+        let answers = answers::Answers::load(&args.answers)?;
+        failed = run::print_table(&reports, &answers, wall);
+    } else {
+        for (report, (_, input)) in reports.iter().zip(&days) {
+            failed += run::print_day(report, input);
+        }
+    }
+
+    if let Some(format) = args.visualize.visualize {
+        let options = visualize::Options {
+            format,
+            out: args.visualize.out,
+            scale: args.visualize.scale.into(),
+            fps: args.visualize.fps,
+        };
+        for report in &reports {
+            for part in &report.parts {
+                let recording = part.recording.as_ref().expect("recorded when visualizing");
+                let label = format!("{} day {} part {}", report.year, report.day, part.part);
+                if recording.frames.is_empty() {
+                    println!("{label}: nothing drawn");
+                    continue;
+                }
+                if recording.dropped > 0 {
+                    println!(
+                        "{label}: kept the first {} frames, dropped {}",
+                        recording.frames.len(),
+                        recording.dropped
+                    );
+                }
+                let name = format!("{}-day{:02}-part{}", report.year, report.day, part.part);
+                visualize::output(&recording.frames, &name, &options)?;
+            }
+        }
+    }
+
+    if failed > 0 && args.selection.all {
+        return Err(format!(
+            "{failed} part(s) don't match their recorded answer"
+        ));
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{failed} part(s) panicked or could not parse the input"
+        ));
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let parts = args.selection.parts();
+    let mut results = Vec::new();
+    let mut failed = 0;
+    for (solution, input) in args.selection.load()? {
+        let (day_results, errors) = bench::bench_day(solution, &input, &parts, args.runs as usize);
+        results.extend(day_results);
+        // stdout is kept for the results, so a part that didn't run is reported on stderr
+        for (part, failure) in errors {
+            let why = match failure {
+                bench::Failure::Parse(e) => {
+                    format!(
+                        "could not parse the input\n{}",
+                        run::indent(&e.report(&input))
+                    )
+                }
+                bench::Failure::Panic(message) => format!("panicked: {message}"),
+            };
+            eprintln!(
+                "{} day {} part {part}: {why}",
+                solution.year(),
+                solution.day()
+            );
+            failed += 1;
+        }
+    }
+
+    bench::write(&results, args.format, &mut io::stdout().lock()).map_err(|e| e.to_string())?;
+
+    if let Some(path) = &args.baseline {
+        let baseline = bench::load_baseline(path)?;
+        let comparisons = bench::compare(&results, &baseline);
+        let regressions = bench::print_comparison(&comparisons, args.threshold);
+        if regressions > 0 {
+            return Err(format!(
+                "{regressions} part(s) regressed by more than {}%",
+                args.threshold
+            ));
+        }
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{failed} part(s) panicked or could not parse the input"
+        ));
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let mut answers = answers::Answers::load(&args.answers)?;
+    let solutions = registry::SOLUTIONS.iter().filter(|s| {
+        args.year.is_none_or(|year| s.year() == year) && args.day.is_none_or(|day| s.day() == day)
+    });
+
+    let (mut correct, mut failed, mut unrecorded, mut skipped) = (0, 0, 0, 0);
+    for &solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = match input::Source::Default.load(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{year} day {day}: skipped, {e}");
+                skipped += 1;
+                continue;
+            }
+        };
+
+        for check in answers::check(solution, &input, &answers) {
+            let label = format!("{year} day {day} part {}", check.part);
+            let answer = check.answer.as_ref().map_or("", Answer::as_str);
+            match check.outcome {
+                answers::Outcome::Correct => {
+                    correct += 1;
+                    println!("{label}: ok");
+                }
+                answers::Outcome::Wrong { expected } => {
+                    failed += 1;
+                    println!("{label}: WRONG, expected {expected} but got {answer}");
+                }
+                answers::Outcome::Missing { expected } => {
+                    failed += 1;
+                    println!("{label}: MISSING, expected {expected} but the part is unsolved");
+                }
+                answers::Outcome::Failed { error } => {
+                    failed += 1;
+                    println!("{label}: FAILED, {}", error.report(&input));
+                }
+                answers::Outcome::Panicked { message } => {
+                    failed += 1;
+                    println!("{label}: PANICKED, {message}");
+                }
+                answers::Outcome::Unrecorded if args.record => {
+                    unrecorded += 1;
+                    println!("{label}: recorded {answer}");
+                    answers.insert(year, day, check.part, answer.to_string());
+                }
+                answers::Outcome::Unrecorded => {
+                    unrecorded += 1;
+                    println!("{label}: no answer on record (got {answer})");
+                }
+            }
+        }
+    }
+
+    if args.record && unrecorded > 0 {
+        answers.save(&args.answers)?;
+    }
+    println!("\n{correct} ok, {failed} failed, {unrecorded} unrecorded, {skipped} days skipped");
+    if failed > 0 {
+        return Err(format!(
+            "{failed} part(s) no longer match their recorded answer"
+        ));
+    }
+    Ok(())
+}
+
+// Talks to the real site unless AOC_BASE_URL points somewhere else.
+fn site_client() -> Result<client::Client<client::HttpTransport>, String> {
+    let cache = input::cache_dir().ok_or("no cache directory: set AOC_CACHE_DIR")?;
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| client::BASE_URL.to_string());
+    Ok(client::Client::new(
+        client::HttpTransport::new(),
+        &base_url,
+        client::session_token()?,
+        client::Throttle::new(cache.join("last-request"), client::MIN_DELAY),
+    ))
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let cache = input::cache_dir().ok_or("no cache directory: set AOC_CACHE_DIR")?;
+    let path = input::cache_path(&cache, args.year, args.day);
+    if path.is_file() && !args.force {
+        println!("{} is already cached", path.display());
+        return Ok(());
+    }
+
+    let input = site_client()?.input(args.year, args.day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    }
+    fs::write(&path, input).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    println!("saved {}", path.display());
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let part = Part::from_number(args.part).expect("clap checks the part");
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let solution = registry::find(year, day)
+                .ok_or_else(|| format!("{year} day {day} is not registered"))?;
+            let input = input::Source::Default
+                .load(year, day)
+                .map_err(|e| e.to_string())?;
+            let answer = solution
+                .solve(part, &input)
+                .ok_or_else(|| format!("{year} day {day} part {part} is unsolved"))?
+                .map_err(|e| e.report(&input))?;
+            answer.to_string()
+        }
+    };
+
+    let mut answers = answers::Answers::load(&args.answers)?;
+    match answers.get(year, day, part) {
+        Some(known) if known == answer => {
+            println!("{answer} is already the accepted answer");
+            return Ok(());
+        }
+        Some(known) => return Err(format!("already solved, the answer was {known}")),
+        None => {}
+    }
+    if let Some(hint) = answers.wrong(year, day, part, &answer) {
+        return Err(match hint {
+            "" => format!("{answer} was already rejected"),
+            hint => format!("{answer} was already rejected as {hint}"),
+        });
+    }
+
+    let cache = input::cache_dir().ok_or("no cache directory: set AOC_CACHE_DIR")?;
+    let cooldown = submit::Cooldown::new(cache.join("submit-cooldown"));
+    if let Some(remaining) = cooldown.remaining() {
+        return Err(format!(
+            "the site asked us to wait, try again in {}s",
+            remaining.as_secs() + 1
+        ));
+    }
+
+    println!("submitting {answer} for {year} day {day} part {part}");
+    let page = site_client()?.submit(year, day, part, &answer)?;
+    let verdict = submit::Verdict::parse(&page);
+    if let Some(wait) = verdict.wait() {
+        cooldown.start(wait)?;
+    }
+
+    match verdict {
+        submit::Verdict::Correct => {
+            answers.insert(year, day, part, answer);
+            answers.save(&args.answers)?;
+            println!("correct!");
+            Ok(())
+        }
+        submit::Verdict::Wrong { hint, wait } => {
+            let mut message = format!("{answer} is wrong");
+            if let Some(hint) = hint {
+                message.push_str(&format!(", {hint}"));
+            }
+            if let Some(wait) = wait {
+                message.push_str(&format!(", wait {}s before trying again", wait.as_secs()));
+            }
+            let hint = hint.map(|h| h.to_string()).unwrap_or_default();
+            answers.insert_wrong(year, day, part, answer, hint);
+            answers.save(&args.answers)?;
+            Err(message)
+        }
+        submit::Verdict::TooRecent { wait } => Err(format!(
+            "answered too recently, wait {}s before trying again",
+            wait.as_secs()
+        )),
+        submit::Verdict::WrongLevel => {
+            Err("the site isn't accepting answers for this part, is it already solved?".into())
+        }
+        submit::Verdict::Unknown(text) => Err(format!("unrecognised response: {text}")),
+    }
+}
+
+fn new(args: NewArgs) -> Result<(), String> {
+    let root = input::repo_root();
+    for path in scaffold::new_day(&root, args.year, args.day)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "next: paste the example into examples/example.txt and run `aoc fetch {} {}`",
+        args.year, args.day
+    );
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let solution =
+        registry::find(year, day).ok_or_else(|| format!("{year} day {day} is not registered"))?;
+    let seed = args.seed.unwrap_or_else(|| {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH);
+        let seed = now.map_or(0, |now| now.as_nanos() as u64);
+        eprintln!("seed {seed}");
+        seed
+    });
+    let input = solution
+        .generate(&mut Rng::new(seed), args.size)
+        .ok_or_else(|| format!("{year} day {day} has no input generator"))?;
+    match &args.out {
+        Some(path) => {
+            fs::write(path, input).map_err(|e| format!("could not write {}: {e}", path.display()))
+        }
+        None => io::stdout()
+            .write_all(input.as_bytes())
+            .map_err(|e| e.to_string()),
+    }
+}
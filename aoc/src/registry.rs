@@ -0,0 +1,60 @@
+use aoc_common::Solution;
+
+/// Every solved day, in order. `aoc new` adds new days to this list.
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day7::Day7,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|s| s.year() == year && s.day() == day)
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static dyn Solution> {
+    SOLUTIONS.iter().copied().filter(move |s| s.year() == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_and_unique() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!(
+                (pair[0].year(), pair[0].day()) < (pair[1].year(), pair[1].day()),
+                "{} day {} is registered out of order",
+                pair[1].year(),
+                pair[1].day()
+            );
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2023, 17).map(|s| s.day()), Some(17));
+        assert!(find(2023, 6).is_none());
+    }
+}
@@ -0,0 +1,439 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::visualize::{record, Recording};
+use aoc_common::{measure, Part, PartResult, Solution, Timing};
+
+use crate::answers::{Answers, Outcome};
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: PartResult,
+    /// What the part panicked with, in which case it has no answer.
+    pub panic: Option<String>,
+    pub timing: Timing,
+    /// The frames the part drew, when they were being recorded.
+    pub recording: Option<Recording>,
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn elapsed(&self) -> Duration {
+        self.parts.iter().map(|p| p.timing.total()).sum()
+    }
+}
+
+/// Runs the parts, recording up to `max_frames` frames of each if given. Drawing the frames
+/// counts towards the timings. A part that panics is reported with the panic's message, and
+/// the other parts still run.
+pub fn run_day(
+    solution: &dyn Solution,
+    input: &str,
+    parts: &[Part],
+    max_frames: Option<usize>,
+) -> DayReport {
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let solve = || {
+                measure(|| panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input))))
+            };
+            let ((result, timing), recording) = match max_frames {
+                Some(limit) => {
+                    let (result, recording) = record(limit, solve);
+                    (result, Some(recording))
+                }
+                None => (solve(), None),
+            };
+            let (answer, panic) = match result {
+                Ok(answer) => (answer, None),
+                Err(payload) => (None, Some(panic_message(payload.as_ref()))),
+            };
+            PartReport {
+                part,
+                answer,
+                panic,
+                timing,
+                recording,
+            }
+        })
+        .collect();
+
+    DayReport {
+        year: solution.year(),
+        day: solution.day(),
+        parts,
+    }
+}
+
+/// The message a panic was raised with, if it was given one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "no message".to_string()
+    }
+}
+
+/// Runs every day on up to `jobs` threads, each taking the next day nobody has started. The
+/// reports are in the same order as `days`.
+pub fn run_days(
+    days: &[(&dyn Solution, String)],
+    parts: &[Part],
+    jobs: usize,
+    max_frames: Option<usize>,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((solution, input)) = days.get(i) else {
+                    break;
+                };
+                let report = run_day(*solution, input, parts, max_frames);
+                finished.lock().unwrap().push((i, report));
+            });
+        }
+    });
+
+    let mut finished = finished.into_inner().unwrap();
+    finished.sort_by_key(|&(i, _)| i);
+    finished.into_iter().map(|(_, report)| report).collect()
+}
+
+/// Prints each part's answer, or why it has none. Returns how many parts panicked or could
+/// not parse the input.
+pub fn print_day(report: &DayReport, input: &str) -> usize {
+    println!("{} day {}", report.year, report.day);
+    let mut failed = 0;
+    for part in &report.parts {
+        if let Some(message) = &part.panic {
+            println!("  part {}: panicked: {message}", part.part);
+            failed += 1;
+            continue;
+        }
+        match &part.answer {
+            Some(Ok(answer)) => println!(
+                "  part {}: {answer} (parse {:.2?}, solve {:.2?})",
+                part.part, part.timing.parse, part.timing.solve
+            ),
+            Some(Err(e)) => {
+                println!(
+                    "  part {}: could not parse the input\n{}",
+                    part.part,
+                    indent(&e.report(input))
+                );
+                failed += 1;
+            }
+            None => println!("  part {}: unsolved", part.part),
+        }
+    }
+    failed
+}
+
+pub fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// One part's row of the summary table
+#[derive(Debug, PartialEq)]
+struct Row {
+    day: u8,
+    part: Part,
+    answer: String,
+    check: String,
+    timing: Timing,
+    passed: bool,
+}
+
+// Every part that was run, slowest first
+fn rows(reports: &[DayReport], answers: &Answers) -> Vec<Row> {
+    let mut rows: Vec<Row> = reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(|part| {
+                let answer = match &part.answer {
+                    Some(Ok(answer)) => answer.to_string(),
+                    Some(Err(_)) => "parse error".to_string(),
+                    None if part.panic.is_some() => "panicked".to_string(),
+                    None => "-".to_string(),
+                };
+                let outcome = answers.compare(report.year, report.day, part.part, &part.answer);
+                let check = match &outcome {
+                    _ if part.panic.is_some() => "PANICKED".to_string(),
+                    Some(Outcome::Panicked { .. }) => "PANICKED".to_string(),
+                    None => String::new(),
+                    Some(Outcome::Correct) => "ok".to_string(),
+                    Some(Outcome::Wrong { expected }) => format!("WRONG, expected {expected}"),
+                    Some(Outcome::Missing { expected }) => format!("MISSING, expected {expected}"),
+                    Some(Outcome::Failed { .. }) => "FAILED".to_string(),
+                    Some(Outcome::Unrecorded) => "unrecorded".to_string(),
+                };
+                let passed = part.panic.is_none()
+                    && !matches!(
+                        outcome,
+                        Some(
+                            Outcome::Wrong { .. }
+                                | Outcome::Missing { .. }
+                                | Outcome::Failed { .. }
+                                | Outcome::Panicked { .. }
+                        )
+                    );
+                Row {
+                    day: report.day,
+                    part: part.part,
+                    answer,
+                    check,
+                    timing: part.timing,
+                    passed,
+                }
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        b.timing
+            .total()
+            .cmp(&a.timing.total())
+            .then((a.day, a.part).cmp(&(b.day, b.part)))
+    });
+    rows
+}
+
+/// Prints every part run as a row, slowest first, checked against `answers`. Returns how many
+/// parts failed their check.
+pub fn print_table(reports: &[DayReport], answers: &Answers, wall: Duration) -> usize {
+    let rows = rows(reports, answers);
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                row.part.to_string(),
+                row.answer.clone(),
+                row.check.clone(),
+                format!("{:.2?}", row.timing.parse),
+                format!("{:.2?}", row.timing.solve),
+            ]
+        })
+        .collect();
+
+    let header = ["day", "part", "answer", "check", "parse", "solve"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = |cells: &[String; 6]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    line(&header);
+    println!("{}", widths.map(|w| "-".repeat(w)).join("-|-"));
+    for row in &cells {
+        line(row);
+    }
+
+    let failed = rows.iter().filter(|row| !row.passed).count();
+    let total: Duration = reports.iter().map(DayReport::elapsed).sum();
+    println!(
+        "\n{} days, {} parts failed, {:.2?} of solving in {:.2?}",
+        reports.len(),
+        failed,
+        total,
+        wall
+    );
+    failed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answer;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> PartResult {
+            Some(Ok(input.into()))
+        }
+
+        fn part_2(&self, input: &str) -> PartResult {
+            Some(aoc_common::parse::parse::<u32>(input, "a number").map(Into::into))
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let report = run_day(&Echo, "hello", &Part::BOTH, None);
+        assert_eq!(report.day, 1);
+        assert_eq!(report.parts[0].answer, Some(Ok(Answer::from("hello"))));
+        let error = report.parts[1].answer.clone().unwrap().unwrap_err();
+        assert_eq!(error.position, Some((1, 1)));
+        assert_eq!(print_day(&report, "hello"), 1);
+    }
+
+    // Answers with its own day number, after sleeping that many milliseconds
+    struct Sleepy(u8);
+
+    impl Solution for Sleepy {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            self.0
+        }
+
+        fn part_1(&self, _input: &str) -> PartResult {
+            thread::sleep(Duration::from_millis(self.0.into()));
+            Some(Ok(u32::from(self.0).into()))
+        }
+    }
+
+    // Panics on part 1 and answers part 2
+    struct Panicky;
+
+    impl Solution for Panicky {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn part_1(&self, input: &str) -> PartResult {
+            panic!("can't handle {input:?}");
+        }
+
+        fn part_2(&self, _input: &str) -> PartResult {
+            Some(Ok(2_u32.into()))
+        }
+    }
+
+    #[test]
+    fn test_panics_are_reported() {
+        let days: Vec<(&dyn Solution, String)> =
+            vec![(&Echo, "hi".to_string()), (&Panicky, "hi".to_string())];
+        let reports = run_days(&days, &Part::BOTH, 2, None);
+        assert_eq!(reports[0].parts[0].answer, Some(Ok(Answer::from("hi"))));
+        let panicky = &reports[1].parts;
+        assert_eq!(panicky[0].answer, None);
+        assert_eq!(panicky[0].panic.as_deref(), Some("can't handle \"hi\""));
+        assert_eq!(panicky[1].answer, Some(Ok(Answer::from(2_u32))));
+        assert_eq!(print_day(&reports[1], "hi"), 1);
+
+        let rows = rows(&reports[1..], &Answers::default());
+        let failed: Vec<_> = rows
+            .iter()
+            .map(|row| (row.part.number(), row.check.as_str(), row.passed))
+            .collect();
+        assert!(failed.contains(&(1, "PANICKED", false)), "{failed:?}");
+        assert!(failed.contains(&(2, "unrecorded", true)), "{failed:?}");
+    }
+
+    #[test]
+    fn test_run_days() {
+        let solutions = [Sleepy(9), Sleepy(1), Sleepy(5)];
+        let days: Vec<(&dyn Solution, String)> = solutions
+            .iter()
+            .map(|s| (s as &dyn Solution, String::new()))
+            .collect();
+        for jobs in [1, 2, 8] {
+            let reports = run_days(&days, &[Part::One], jobs, None);
+            let answers: Vec<_> = reports
+                .iter()
+                .map(|r| (r.day, r.parts[0].answer.clone()))
+                .collect();
+            assert_eq!(
+                answers,
+                [9, 1, 5].map(|day| (day, Some(Ok(Answer::from(u32::from(day))))))
+            );
+        }
+        assert!(run_days(&[], &[Part::One], 4, None).is_empty());
+    }
+
+    #[test]
+    fn test_rows() {
+        let part = |part, answer: PartResult, millis| PartReport {
+            part,
+            answer,
+            panic: None,
+            timing: Timing {
+                parse: Duration::ZERO,
+                solve: Duration::from_millis(millis),
+            },
+            recording: None,
+        };
+        let report = |day, parts| DayReport {
+            year: 2023,
+            day,
+            parts,
+        };
+        let reports = [
+            report(
+                3,
+                vec![
+                    part(Part::One, Some(Ok(Answer::from(4_u32))), 5),
+                    part(Part::Two, None, 1),
+                ],
+            ),
+            report(
+                7,
+                vec![
+                    part(Part::One, Some(Ok(Answer::from(8_u32))), 20),
+                    part(Part::Two, Some(Ok(Answer::from(9_u32))), 5),
+                ],
+            ),
+        ];
+        let answers = Answers::parse("2023 3 1 4\n2023 7 1 10\n2023 3 2 6\n").unwrap();
+
+        let rows = rows(&reports, &answers);
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| (row.day, row.part.number(), row.check.as_str(), row.passed))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (7, 1, "WRONG, expected 10", false),
+                (3, 1, "ok", true),
+                (7, 2, "unrecorded", true),
+                (3, 2, "MISSING, expected 6", false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates the crate for a new day under `root` and wires it into the workspace and the
+/// runner. Returns every file it created or changed.
+///
+/// Every edit is worked out before anything is written, so nothing is written if one of them
+/// can't be made. A write that fails partway through can still leave some files changed.
+/// The crate is named like the year's other days, like `day5` or `aoc-2024-day5`.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(year.to_string()).join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let workspace_path = root.join("Cargo.toml");
+    let manifest_path = root.join("aoc").join("Cargo.toml");
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    let workspace = add_member(&read(&workspace_path)?, year)?;
+    let manifest = read(&manifest_path)?;
+    let name = package_name(&manifest, year, day);
+    let registry = register(&read(&registry_path)?, &manifest, year, day)?;
+    let manifest = add_dependency(&manifest, year, day)?;
+
+    let mut changed = vec![
+        (dir.join("Cargo.toml"), cargo_toml(&name)),
+        (dir.join("src").join("lib.rs"), lib_rs(year, day)),
+        (dir.join("src").join("generate.rs"), generate_rs(day)),
+        (
+            dir.join("examples").join("example.txt"),
+            EXAMPLE.to_string(),
+        ),
+        (manifest_path, manifest),
+        (registry_path, registry),
+    ];
+    if let Some(workspace) = workspace {
+        changed.push((workspace_path, workspace));
+    }
+
+    for (path, text) in &changed {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        }
+        fs::write(path, text).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    }
+    Ok(changed.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+}
+
+// A single unanswered part, so the example test fails until the real example is pasted in
+const EXAMPLE: &str = "part 1: ?\n\n";
+
+// The package name of a new day's crate, going by the days the runner's `manifest` already
+// depends on. A year keeps the scheme its days have, and the first year's days are named like
+// `day5`. Any later year's are named with the year, like `aoc-2024-day5`, so they don't clash
+// with the first's.
+fn package_name(manifest: &str, year: u16, day: u8) -> String {
+    let deps = day_dependencies(manifest);
+    let plain = match deps.iter().find(|&&(_, y, _)| y == year) {
+        Some(&(name, _, d)) => name == format!("day{d}"),
+        None => deps.is_empty(),
+    };
+    if plain {
+        format!("day{day}")
+    } else {
+        format!("aoc-{year}-day{day}")
+    }
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common.workspace = true
+"#
+    )
+}
+
+fn lib_rs(year: u16, day: u8) -> String {
+    format!(
+        r#"use aoc_common::generate::Rng;
+use aoc_common::{{parse_phase, Answer, ParseError, PartResult, Solution}};
+
+pub mod generate;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    fn year(&self) -> u16 {{
+        {year}
+    }}
+
+    fn day(&self) -> u8 {{
+        {day}
+    }}
+
+    fn part_1(&self, input: &str) -> PartResult {{
+        part_1(input).transpose().map(|r| r.map(Answer::from))
+    }}
+
+    fn part_2(&self, input: &str) -> PartResult {{
+        part_2(input).transpose().map(|r| r.map(Answer::from))
+    }}
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {{
+        aoc_common::generate::generate_sized::<generate::Params>(rng, size)
+    }}
+}}
+
+// Unsolved until it returns `Ok(Some(answer))`
+pub fn part_1(input: &str) -> Result<Option<u64>, ParseError> {{
+    let _lines = deserialize(input)?;
+    Ok(None)
+}}
+
+pub fn part_2(input: &str) -> Result<Option<u64>, ParseError> {{
+    let _lines = deserialize(input)?;
+    Ok(None)
+}}
+
+fn deserialize(input: &str) -> Result<Vec<&str>, ParseError> {{
+    parse_phase(|| Ok(input.lines().collect()))
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    aoc_common::example_tests!(Day{day});
+    aoc_common::generated_tests!(Day{day}, [1, 10, 100]);
+}}
+"#
+    )
+}
+
+fn generate_rs(day: u8) -> String {
+    format!(
+        r#"//! Random inputs for day {day}.
+
+use aoc_common::generate::{{Generator, Rng}};
+
+/// The shape of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {{
+    pub lines: usize,
+}}
+
+impl Default for Params {{
+    fn default() -> Self {{
+        Params {{ lines: 100 }}
+    }}
+}}
+
+impl Generator for Params {{
+    fn sized(lines: usize) -> Self {{
+        Params {{ lines }}
+    }}
+
+    fn generate(&self, rng: &mut Rng) -> String {{
+        generate(rng, self)
+    }}
+}}
+
+/// Lines of random numbers, until it makes inputs like the real ones.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {{
+    let mut input = String::new();
+    for _ in 0..params.lines {{
+        input.push_str(&rng.range(0..1000_u32).to_string());
+        input.push('\n');
+    }}
+    input
+}}
+"#
+    )
+}
+
+// Adds `"<year>/day*"` to the workspace members, or returns None if it's already there.
+fn add_member(workspace: &str, year: u16) -> Result<Option<String>, String> {
+    let member = format!("\"{year}/day*\"");
+    let start = workspace
+        .find("members = [")
+        .ok_or("no `members = [...]` in the workspace Cargo.toml")?;
+    let end = start
+        + workspace[start..]
+            .find(']')
+            .ok_or("unterminated workspace members")?;
+    if workspace[start..end].contains(&member) {
+        return Ok(None);
+    }
+    Ok(Some(format!(
+        "{}, {member}{}",
+        &workspace[..end],
+        &workspace[end..]
+    )))
+}
+
+// The day crates the runner depends on, as `(crate, year, day)`, from lines like
+// `day17 = { path = "../2023/day17" }` or `aoc-2024-day5 = { path = "../2024/day5" }`.
+fn day_dependencies(manifest: &str) -> Vec<(&str, u16, u8)> {
+    manifest
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(" = ")?;
+            let path = rest.strip_prefix("{ path = \"../")?.strip_suffix("\" }")?;
+            let (year, dir) = path.split_once('/')?;
+            let day = dir.strip_prefix("day")?;
+            Some((name, year.parse().ok()?, day.parse().ok()?))
+        })
+        .collect()
+}
+
+// Inserts `line` after the last line of `text` whose key sorts before `key`, or at index
+// `last` if there is none.
+fn insert_sorted(
+    text: &str,
+    line: String,
+    keys: impl Fn(&str) -> Option<(u16, u8)>,
+    key: (u16, u8),
+    last: usize,
+) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let at = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| keys(l).is_some_and(|k| k < key))
+        .map(|(i, _)| i + 1)
+        .last()
+        .unwrap_or(last);
+    lines.insert(at, &line);
+    lines.join("\n") + "\n"
+}
+
+// Adds the new day as a path dependency of the runner, keeping the days in order.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let name = package_name(manifest, year, day);
+    let deps = day_dependencies(manifest);
+    if let Some((other, _, _)) = deps.iter().find(|&&(_, y, d)| (y, d) == (year, day)) {
+        return Err(format!(
+            "the runner already depends on {year} day {day}, as {other}"
+        ));
+    }
+
+    let first = manifest
+        .lines()
+        .position(|l| day_dependencies(l).len() == 1)
+        .or_else(|| {
+            manifest
+                .lines()
+                .position(|l| l == "[dependencies]")
+                .map(|i| i + 1)
+        })
+        .ok_or("no [dependencies] in aoc/Cargo.toml")?;
+    Ok(insert_sorted(
+        manifest,
+        format!("{name} = {{ path = \"../{year}/day{day}\" }}"),
+        |line| day_dependencies(line).first().map(|&(_, y, d)| (y, d)),
+        (year, day),
+        first,
+    ))
+}
+
+// Adds `&dayN::DayN,` or `&aoc_YEAR_dayN::DayN,` to `SOLUTIONS`, using the runner's manifest to know each entry's
+// year. Rust names a crate after its package with `-` replaced by `_`.
+fn register(registry: &str, manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let deps = day_dependencies(manifest);
+    let key = |line: &str| {
+        let name = line.trim().strip_prefix('&')?.split("::").next()?;
+        deps.iter()
+            .find(|(n, _, _)| n.replace('-', "_") == name)
+            .map(|&(_, y, d)| (y, d))
+    };
+
+    let start = registry
+        .lines()
+        .position(|l| l.contains("SOLUTIONS"))
+        .ok_or("no SOLUTIONS list in registry.rs")?;
+    Ok(insert_sorted(
+        registry,
+        format!(
+            "    &{}::Day{day},",
+            package_name(manifest, year, day).replace('-', "_")
+        ),
+        key,
+        (year, day),
+        start + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const WORKSPACE: &str = "[workspace]\nmembers = [\"aoc\", \"2023/day*\"]\n";
+    const MANIFEST: &str = "[dependencies]\naoc-common.workspace = true\nday1 = { path = \"../2023/day1\" }\nday5 = { path = \"../2023/day5\" }\n";
+    const REGISTRY: &str =
+        "pub static SOLUTIONS: &[&dyn Solution] = &[\n    &day1::Day1,\n    &day5::Day5,\n];\n";
+
+    #[test]
+    fn test_add_member() {
+        assert_eq!(add_member(WORKSPACE, 2023).unwrap(), None);
+        assert_eq!(
+            add_member(WORKSPACE, 2024).unwrap().unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"2023/day*\", \"2024/day*\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        assert_eq!(
+            add_dependency(MANIFEST, 2023, 3).unwrap(),
+            "[dependencies]\naoc-common.workspace = true\nday1 = { path = \"../2023/day1\" }\nday3 = { path = \"../2023/day3\" }\nday5 = { path = \"../2023/day5\" }\n"
+        );
+        assert!(add_dependency(MANIFEST, 2024, 5).unwrap().ends_with(
+            "day5 = { path = \"../2023/day5\" }\naoc-2024-day5 = { path = \"../2024/day5\" }\n"
+        ));
+        assert_eq!(
+            add_dependency(MANIFEST, 2023, 5).unwrap_err(),
+            "the runner already depends on 2023 day 5, as day5"
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\nclap = \"4\"\n", 2023, 1).unwrap(),
+            "[dependencies]\nday1 = { path = \"../2023/day1\" }\nclap = \"4\"\n"
+        );
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(package_name("", 2024, 5), "day5");
+        assert_eq!(package_name(MANIFEST, 2023, 3), "day3");
+        assert_eq!(package_name(MANIFEST, 2024, 5), "aoc-2024-day5");
+        // Each year keeps its own scheme once both are there
+        let manifest = add_dependency(MANIFEST, 2024, 1).unwrap();
+        assert_eq!(package_name(&manifest, 2024, 2), "aoc-2024-day2");
+        assert_eq!(package_name(&manifest, 2023, 3), "day3");
+    }
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(REGISTRY, MANIFEST, 2023, 2).unwrap(),
+            "pub static SOLUTIONS: &[&dyn Solution] = &[\n    &day1::Day1,\n    &day2::Day2,\n    &day5::Day5,\n];\n"
+        );
+        // Days of a later year go after every day of an earlier one, even a new one's
+        let manifest = add_dependency(MANIFEST, 2024, 1).unwrap();
+        let registry = register(REGISTRY, MANIFEST, 2024, 1).unwrap();
+        assert!(register(&registry, &manifest, 2024, 5).unwrap().ends_with(
+            "    &day5::Day5,\n    &aoc_2024_day1::Day1,\n    &aoc_2024_day5::Day5,\n];\n"
+        ));
+        assert_eq!(
+            register(
+                "pub static SOLUTIONS: &[&dyn Solution] = &[\n];\n",
+                "",
+                2023,
+                2
+            )
+            .unwrap(),
+            "pub static SOLUTIONS: &[&dyn Solution] = &[\n    &day2::Day2,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("aoc").join("src").join("registry.rs"), REGISTRY).unwrap();
+
+        let changed = new_day(&root, 2023, 22).unwrap();
+        assert_eq!(changed.len(), 6);
+        let lib = fs::read_to_string(root.join("2023/day22/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day22;"));
+        assert!(lib.contains("aoc_common::example_tests!(Day22);"));
+        assert!(lib.contains("aoc_common::generated_tests!(Day22, [1, 10, 100]);"));
+        assert!(root.join("2023/day22/src/generate.rs").exists());
+        assert!(!lib.contains("allow") && !lib.contains("todo!"));
+        // Both parts are unsolved until someone answers them
+        assert_eq!(lib.matches("    Ok(None)\n").count(), 2);
+        assert_eq!(
+            lib.matches(".transpose().map(|r| r.map(Answer::from))")
+                .count(),
+            2
+        );
+        assert!(!lib.contains("Some(part_"));
+        assert!(fs::read_to_string(root.join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("    &day5::Day5,\n    &day22::Day22,\n];"));
+        let manifest = fs::read_to_string(root.join("2023/day22/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day22\""));
+
+        // A day of another year with the same number
+        assert_eq!(new_day(&root, 2024, 5).unwrap().len(), 7);
+        let manifest = fs::read_to_string(root.join("2024/day5/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc-2024-day5\""));
+
+        assert!(new_day(&root, 2023, 22)
+            .unwrap_err()
+            .ends_with("day22 already exists"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    TooRecent {
+        wait: Duration,
+    },
+    // The part was already solved, or part 2 was submitted before part 1
+    WrongLevel,
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => f.write_str("too high"),
+            Hint::TooLow => f.write_str("too low"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned for a submission.
+    pub fn parse(page: &str) -> Self {
+        let text = article_text(page);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Verdict::Wrong {
+                hint,
+                wait: penalty(&text),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::TooRecent {
+                wait: time_left(&text).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    /// How long the site wants us to wait before the next submission.
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            Verdict::Wrong { wait, .. } => *wait,
+            Verdict::TooRecent { wait } => Some(*wait),
+            _ => None,
+        }
+    }
+}
+
+// The text of the page's <article>, where the verdict is, without markup.
+fn article_text(page: &str) -> String {
+    let start = page.find("<article>").unwrap_or(0);
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "Please wait one minute before trying again" / "Please wait 5 minutes before trying again"
+fn penalty(text: &str) -> Option<Duration> {
+    let after = text.split("Please wait ").nth(1)?;
+    let amount = after.split(" before trying again").next()?;
+    let (count, unit) = amount.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    let seconds = if unit.starts_with("minute") { 60 } else { 1 };
+    Some(Duration::from_secs(count * seconds))
+}
+
+// "You have 4m 12s left to wait" / "You have 39s left to wait"
+fn time_left(text: &str) -> Option<Duration> {
+    let after = text.split("You have ").nth(1)?;
+    let amount = after.split(" left to wait").next()?;
+    let mut seconds = 0;
+    for token in amount.split_whitespace() {
+        let (number, scale) = [("h", 3600), ("m", 60), ("s", 1)]
+            .into_iter()
+            .find_map(|(unit, scale)| Some((token.strip_suffix(unit)?, scale)))?;
+        let number: u64 = number.parse().ok()?;
+        seconds += number * scale;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Remembers when the site will accept the next submission, across runs of the tool.
+pub struct Cooldown {
+    path: PathBuf,
+}
+
+impl Cooldown {
+    pub fn new(path: PathBuf) -> Self {
+        Cooldown { path }
+    }
+
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    pub fn remaining(&self) -> Option<Duration> {
+        let until = fs::read_to_string(&self.path).ok()?.trim().parse().ok()?;
+        Duration::from_millis(until)
+            .checked_sub(Self::now())
+            .filter(|d| !d.is_zero())
+    }
+
+    pub fn start(&self, wait: Duration) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        }
+        let until = (Self::now() + wait).as_millis();
+        fs::write(&self.path, until.to_string())
+            .map_err(|e| format!("could not write {}: {e}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+    use crate::client::{Client, HttpTransport, Throttle};
+    use aoc_common::Part;
+    use std::env;
+
+    const CORRECT: &str = include_str!("../fixtures/submit/correct.html");
+    const TOO_HIGH: &str = include_str!("../fixtures/submit/too_high.html");
+    const TOO_LOW: &str = include_str!("../fixtures/submit/too_low.html");
+    const WRONG: &str = include_str!("../fixtures/submit/wrong.html");
+    const TOO_RECENT: &str = include_str!("../fixtures/submit/too_recent.html");
+    const WRONG_LEVEL: &str = include_str!("../fixtures/submit/wrong_level.html");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Verdict::parse(CORRECT), Verdict::Correct);
+        assert_eq!(
+            Verdict::parse(TOO_HIGH),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            Verdict::parse(TOO_LOW),
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            Verdict::parse(WRONG),
+            Verdict::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            Verdict::parse(TOO_RECENT),
+            Verdict::TooRecent {
+                wait: Duration::from_secs(4 * 60 + 12)
+            }
+        );
+        assert_eq!(Verdict::parse(WRONG_LEVEL), Verdict::WrongLevel);
+        assert_eq!(
+            Verdict::parse("<article><p>Something <em>new</em></p></article>"),
+            Verdict::Unknown("Something new".to_string())
+        );
+    }
+
+    #[test]
+    fn test_time_left() {
+        assert_eq!(
+            time_left("You have 1h 4m 12s left to wait."),
+            Some(Duration::from_secs(3600 + 4 * 60 + 12))
+        );
+        // The text comes from the server, so it may end a token in anything
+        assert_eq!(time_left("You have 5é left to wait."), None);
+        assert_eq!(time_left("You have ⏳ left to wait."), None);
+    }
+
+    #[test]
+    fn test_submit_replays() {
+        let server = MockServer::start(vec![(200, TOO_LOW), (200, TOO_RECENT), (200, CORRECT)]);
+        let stamp = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let client = Client::new(
+            HttpTransport::new(),
+            &server.url(),
+            "abc123".to_string(),
+            Throttle::new(stamp.clone(), Duration::ZERO),
+        );
+
+        let verdicts: Vec<Verdict> = ["5", "6", "7"]
+            .iter()
+            .map(|answer| Verdict::parse(&client.submit(2023, 17, Part::One, answer).unwrap()))
+            .collect();
+        assert!(matches!(
+            verdicts[0],
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                ..
+            }
+        ));
+        assert!(matches!(verdicts[1], Verdict::TooRecent { .. }));
+        assert_eq!(verdicts[2], Verdict::Correct);
+        assert_eq!(server.requests()[2].body, "level=1&answer=7");
+        let _ = fs::remove_file(stamp);
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let path = env::temp_dir().join(format!("aoc-cooldown-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let cooldown = Cooldown::new(path.clone());
+        assert_eq!(cooldown.remaining(), None);
+
+        cooldown.start(Duration::from_secs(60)).unwrap();
+        let remaining = cooldown.remaining().unwrap();
+        assert!(remaining > Duration::from_secs(55) && remaining <= Duration::from_secs(60));
+
+        cooldown.start(Duration::ZERO).unwrap();
+        assert_eq!(cooldown.remaining(), None);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+use tracing::Subscriber;
+use tracing_subscriber::EnvFilter;
+
+/// Sends the trace events allowed by `filter` to stderr, or to a new file at `path`. The
+/// filter takes the `RUST_LOG` syntax, where targets are the day crates: `day20=debug`, or
+/// `day5=trace,day20=debug`, or just `debug` for every day.
+pub fn init(filter: &str, path: Option<&Path>) -> Result<(), String> {
+    let file = path
+        .map(|path| {
+            File::create(path).map_err(|e| format!("could not create {}: {e}", path.display()))
+        })
+        .transpose()?;
+    tracing::subscriber::set_global_default(subscriber(filter, file)?).map_err(|e| e.to_string())
+}
+
+// Events are written without timestamps, and without colors in a file, so that the traces of
+// two runs can be diffed
+fn subscriber(
+    filter: &str,
+    file: Option<File>,
+) -> Result<Box<dyn Subscriber + Send + Sync>, String> {
+    let filter =
+        EnvFilter::try_new(filter).map_err(|e| format!("bad trace filter {filter:?}: {e}"))?;
+    let events = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .without_time();
+    Ok(match file {
+        Some(file) => Box::new(
+            events
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .finish(),
+        ),
+        None => Box::new(events.with_writer(io::stderr).finish()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tracing::{debug, trace};
+
+    #[test]
+    fn test_trace_to_file() {
+        let path = std::env::temp_dir().join(format!("aoc-trace-{}.log", std::process::id()));
+        let file = File::create(&path).unwrap();
+        let subscriber = subscriber("day20=debug", Some(file)).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            debug!(target: "day20", press = 3, input = "mp", "high pulse into dr");
+            trace!(target: "day20", "too detailed for the filter");
+            debug!(target: "day5", "another day");
+        });
+
+        let trace = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            trace,
+            "DEBUG day20: high pulse into dr press=3 input=\"mp\"\n"
+        );
+    }
+
+    #[test]
+    fn test_bad_filter() {
+        let error = subscriber("day20=loud", None).err().unwrap();
+        assert!(
+            error.starts_with("bad trace filter \"day20=loud\""),
+            "{error}"
+        );
+    }
+}
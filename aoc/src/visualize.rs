@@ -0,0 +1,246 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+use aoc_common::grid::Grid;
+use aoc_common::visualize::{Pixel, Rgb};
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Play the frames in the terminal in color
+    Ansi,
+    /// Write every frame as a PPM image
+    Ppm,
+    /// Write every frame as a PNG image
+    Png,
+    /// Write the frames as one animated GIF
+    Gif,
+}
+
+pub struct Options {
+    pub format: Format,
+    pub out: PathBuf,
+    /// The side of the square of pixels each cell is drawn as in images.
+    pub scale: usize,
+    pub fps: u32,
+}
+
+impl Options {
+    fn delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+}
+
+/// Shows or saves the frames of one part, with file names starting with `name`.
+pub fn output(frames: &[Grid<Pixel>], name: &str, options: &Options) -> Result<(), String> {
+    if options.format == Format::Ansi {
+        return play(frames, options.delay(), &mut io::stdout().lock()).map_err(|e| e.to_string());
+    }
+
+    fs::create_dir_all(&options.out)
+        .map_err(|e| format!("could not create {}: {e}", options.out.display()))?;
+    if options.format == Format::Gif {
+        let path = options.out.join(format!("{name}.gif"));
+        write_gif(frames, options.scale, options.delay(), create(&path)?)
+            .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+        println!("wrote {}", path.display());
+        return Ok(());
+    }
+
+    for (i, frame) in frames.iter().enumerate() {
+        let path = options.out.join(format!("{name}-{i:04}"));
+        let result = match options.format {
+            Format::Ppm => write_ppm(frame, options.scale, create(&path.with_extension("ppm"))?),
+            _ => write_png(frame, options.scale, create(&path.with_extension("png"))?),
+        };
+        result.map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    }
+    println!(
+        "wrote {} frame(s) to {}",
+        frames.len(),
+        options.out.display()
+    );
+    Ok(())
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|e| format!("could not create {}: {e}", path.display()))
+}
+
+/// The frame as text in 24-bit ANSI colors, one line per row.
+pub fn to_ansi(frame: &Grid<Pixel>) -> String {
+    let mut text = String::new();
+    for row in frame.rows() {
+        let mut color = None;
+        for pixel in row {
+            if color != Some(pixel.color) {
+                let Rgb(r, g, b) = pixel.color;
+                text.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                color = Some(pixel.color);
+            }
+            text.push(pixel.glyph);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+// Draws each frame over the last, clearing the screen in between
+fn play(frames: &[Grid<Pixel>], delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    for frame in frames {
+        write!(out, "\x1b[H\x1b[2J{}", to_ansi(frame))?;
+        out.flush()?;
+        sleep(delay);
+    }
+    Ok(())
+}
+
+// The frame's colors as RGB bytes, row by row, with each cell a `scale` by `scale` square
+fn rgb_bytes(frame: &Grid<Pixel>, scale: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(frame.width() * frame.height() * scale * scale * 3);
+    for row in frame.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|pixel| {
+                let Rgb(r, g, b) = pixel.color;
+                [r, g, b].repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            bytes.extend_from_slice(&line);
+        }
+    }
+    bytes
+}
+
+/// Writes the frame as a binary PPM image.
+pub fn write_ppm(frame: &Grid<Pixel>, scale: usize, mut out: impl Write) -> io::Result<()> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(&rgb_bytes(frame, scale))?;
+    out.flush()
+}
+
+/// Writes the frame as a PNG image.
+pub fn write_png(frame: &Grid<Pixel>, scale: usize, out: impl Write) -> io::Result<()> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let too_big = || io::Error::other(format!("{width}x{height} is too big for a PNG"));
+    let width = u32::try_from(width).map_err(|_| too_big())?;
+    let height = u32::try_from(height).map_err(|_| too_big())?;
+
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&rgb_bytes(frame, scale))?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Writes the frames as a GIF that loops forever, showing each for `delay`. Every frame must
+/// be the size of the first.
+pub fn write_gif(
+    frames: &[Grid<Pixel>],
+    scale: usize,
+    delay: Duration,
+    out: impl Write,
+) -> Result<(), String> {
+    let first = frames.first().ok_or("no frames to animate")?;
+    let (width, height) = (first.width() * scale, first.height() * scale);
+    let too_big = || format!("{width}x{height} is too big for a GIF");
+    let width = u16::try_from(width).map_err(|_| too_big())?;
+    let height = u16::try_from(height).map_err(|_| too_big())?;
+
+    let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(|e| e.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+    // GIF delays are in hundredths of a second
+    let delay = (delay.as_millis() / 10).clamp(1, u128::from(u16::MAX)) as u16;
+    for (i, frame) in frames.iter().enumerate() {
+        if (frame.width(), frame.height()) != (first.width(), first.height()) {
+            return Err(format!("frame {i} is a different size from the first"));
+        }
+        let mut image = gif::Frame::from_rgb_speed(width, height, &rgb_bytes(frame, scale), 10);
+        image.delay = delay;
+        encoder.write_frame(&image).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkers() -> Grid<Pixel> {
+        let (on, off) = (Pixel::new('#', Rgb::RED), Pixel::new('.', Rgb::BLACK));
+        Grid::from_cells(2, vec![on, off, off, on])
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        let frame = Grid::from_cells(
+            3,
+            vec![
+                Pixel::new('a', Rgb(1, 2, 3)),
+                Pixel::new('b', Rgb(1, 2, 3)),
+                Pixel::new('c', Rgb::WHITE),
+            ],
+        );
+        assert_eq!(
+            to_ansi(&frame),
+            "\x1b[38;2;1;2;3mab\x1b[38;2;255;255;255mc\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut bytes = Vec::new();
+        write_ppm(&checkers(), 2, &mut bytes).unwrap();
+        let (header, pixels) = bytes.split_at(11);
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        // The top right quarter is the black cell
+        assert_eq!(&pixels[..12], [220, 50, 47, 220, 50, 47, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut bytes = Vec::new();
+        write_png(&checkers(), 3, &mut bytes).unwrap();
+
+        let mut reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(pixels, rgb_bytes(&checkers(), 3));
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let frames = [checkers(), checkers().map(|_| Pixel::new(' ', Rgb::BLUE))];
+        let mut bytes = Vec::new();
+        write_gif(&frames, 2, Duration::from_millis(200), &mut bytes).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(bytes.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (4, 4));
+        let mut decoded = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            decoded.push((frame.delay, frame.buffer[..3].to_vec()));
+        }
+        assert_eq!(decoded, [(20, vec![220, 50, 47]), (20, vec![60, 120, 230])]);
+
+        let wider = Grid::filled(3, 2, Pixel::new(' ', Rgb::BLACK));
+        assert_eq!(
+            write_gif(&[checkers(), wider], 1, Duration::ZERO, io::sink()),
+            Err("frame 1 is a different size from the first".to_string())
+        );
+    }
+}